@@ -27,11 +27,8 @@ fn main() {
     let continent_sq_mil_persent =
         CONTINENT_SQUARE_MILES.map(|mil| (mil * 100) as f32 / continent_sq_mil_total as f32);
 
-    for ((continent, sq_miles), sq_persent) in Continents::names()
-        .into_iter()
-        .zip(CONTINENT_SQUARE_MILES)
-        .zip(continent_sq_mil_persent)
-    {
+    for (continent, sq_miles) in CONTINENT_SQUARE_MILES.into_entries() {
+        let sq_persent = continent_sq_mil_persent[continent];
         println!("{continent:15} = {sq_miles:10} (sq mi) {sq_persent:6.2} (%)");
     }
 }
@@ -0,0 +1,249 @@
+use core::iter::FusedIterator;
+
+use crate::{ArrSet, Key};
+
+/// An iterator over map entries, see [`ArrMap::iter_entries`](crate::ArrMap::iter_entries).
+#[derive(Debug, Clone)]
+pub struct Entries<'a, K: 'static, T> {
+    keys: core::slice::Iter<'static, K>,
+    values: core::slice::Iter<'a, T>,
+}
+
+impl<'a, K, T> Entries<'a, K, T> {
+    pub(crate) fn new(keys: &'static [K], values: &'a [T]) -> Self {
+        Self {
+            keys: keys.iter(),
+            values: values.iter(),
+        }
+    }
+}
+
+impl<'a, K: Copy, T> Iterator for Entries<'a, K, T> {
+    type Item = (K, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: Copy, T> DoubleEndedIterator for Entries<'_, K, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K: Copy, T> ExactSizeIterator for Entries<'_, K, T> {}
+
+impl<K: Copy, T> FusedIterator for Entries<'_, K, T> {}
+
+/// A mutable iterator over map entries, see [`ArrMap::iter_entries_mut`](crate::ArrMap::iter_entries_mut).
+#[derive(Debug)]
+pub struct EntriesMut<'a, K: 'static, T> {
+    keys: core::slice::Iter<'static, K>,
+    values: core::slice::IterMut<'a, T>,
+}
+
+impl<'a, K, T> EntriesMut<'a, K, T> {
+    pub(crate) fn new(keys: &'static [K], values: &'a mut [T]) -> Self {
+        Self {
+            keys: keys.iter(),
+            values: values.iter_mut(),
+        }
+    }
+}
+
+impl<'a, K: Copy, T> Iterator for EntriesMut<'a, K, T> {
+    type Item = (K, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: Copy, T> DoubleEndedIterator for EntriesMut<'_, K, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K: Copy, T> ExactSizeIterator for EntriesMut<'_, K, T> {}
+
+impl<K: Copy, T> FusedIterator for EntriesMut<'_, K, T> {}
+
+/// An owning iterator over map entries, see [`ArrMap::into_entries`](crate::ArrMap::into_entries).
+#[derive(Debug, Clone)]
+pub struct IntoEntries<K: 'static, T, const N: usize> {
+    keys: core::slice::Iter<'static, K>,
+    values: core::array::IntoIter<T, N>,
+}
+
+impl<K, T, const N: usize> IntoEntries<K, T, N> {
+    pub(crate) fn new(keys: &'static [K], values: [T; N]) -> Self {
+        Self {
+            keys: keys.iter(),
+            values: values.into_iter(),
+        }
+    }
+}
+
+impl<K: Copy, T, const N: usize> Iterator for IntoEntries<K, T, N> {
+    type Item = (K, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: Copy, T, const N: usize> DoubleEndedIterator for IntoEntries<K, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((*self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K: Copy, T, const N: usize> ExactSizeIterator for IntoEntries<K, T, N> {}
+
+impl<K: Copy, T, const N: usize> FusedIterator for IntoEntries<K, T, N> {}
+
+/// An iterator over set keys, see [`ArrSet::iter`](crate::ArrSet::iter).
+#[derive(Debug, Clone)]
+pub struct SetIter<K: Key, const W: usize> {
+    keys: core::slice::Iter<'static, K>,
+    set: ArrSet<K, W>,
+    len: usize,
+}
+
+impl<K: Key, const W: usize> SetIter<K, W> {
+    pub(crate) fn new(keys: &'static [K], set: ArrSet<K, W>) -> Self {
+        Self {
+            keys: keys.iter(),
+            len: set.len(),
+            set,
+        }
+    }
+}
+
+impl<K: Key, const W: usize> Iterator for SetIter<K, W> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = *self.keys.find(|&&key| self.set.contains(key))?;
+        self.len -= 1;
+        Some(key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: Key, const W: usize> DoubleEndedIterator for SetIter<K, W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let key = *self.keys.rfind(|&&key| self.set.contains(key))?;
+        self.len -= 1;
+        Some(key)
+    }
+}
+
+impl<K: Key, const W: usize> ExactSizeIterator for SetIter<K, W> {}
+
+impl<K: Key, const W: usize> FusedIterator for SetIter<K, W> {}
+
+/// An iterator over subset entries, see [`Subset::iter_entries`](crate::Subset::iter_entries).
+#[derive(Debug, Clone)]
+pub struct SubsetEntries<'a, K: Key, T, const W: usize> {
+    entries: Entries<'a, K, T>,
+    keys: ArrSet<K, W>,
+    len: usize,
+}
+
+impl<'a, K: Key, T, const W: usize> SubsetEntries<'a, K, T, W> {
+    pub(crate) fn new(entries: Entries<'a, K, T>, keys: ArrSet<K, W>) -> Self {
+        Self {
+            entries,
+            len: keys.len(),
+            keys,
+        }
+    }
+}
+
+impl<'a, K: Key, T, const W: usize> Iterator for SubsetEntries<'a, K, T, W> {
+    type Item = (K, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.find(|(key, _)| self.keys.contains(*key))?;
+        self.len -= 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: Key, T, const W: usize> DoubleEndedIterator for SubsetEntries<'_, K, T, W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.rfind(|(key, _)| self.keys.contains(*key))?;
+        self.len -= 1;
+        Some(entry)
+    }
+}
+
+impl<K: Key, T, const W: usize> ExactSizeIterator for SubsetEntries<'_, K, T, W> {}
+
+impl<K: Key, T, const W: usize> FusedIterator for SubsetEntries<'_, K, T, W> {}
+
+/// A mutable iterator over subset entries, see [`SubsetMut::iter_entries_mut`](crate::SubsetMut::iter_entries_mut).
+#[derive(Debug)]
+pub struct SubsetEntriesMut<'a, K: Key, T, const W: usize> {
+    entries: EntriesMut<'a, K, T>,
+    keys: ArrSet<K, W>,
+    len: usize,
+}
+
+impl<'a, K: Key, T, const W: usize> SubsetEntriesMut<'a, K, T, W> {
+    pub(crate) fn new(entries: EntriesMut<'a, K, T>, keys: ArrSet<K, W>) -> Self {
+        Self {
+            entries,
+            len: keys.len(),
+            keys,
+        }
+    }
+}
+
+impl<'a, K: Key, T, const W: usize> Iterator for SubsetEntriesMut<'a, K, T, W> {
+    type Item = (K, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.find(|(key, _)| self.keys.contains(*key))?;
+        self.len -= 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K: Key, T, const W: usize> DoubleEndedIterator for SubsetEntriesMut<'_, K, T, W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.rfind(|(key, _)| self.keys.contains(*key))?;
+        self.len -= 1;
+        Some(entry)
+    }
+}
+
+impl<K: Key, T, const W: usize> ExactSizeIterator for SubsetEntriesMut<'_, K, T, W> {}
+
+impl<K: Key, T, const W: usize> FusedIterator for SubsetEntriesMut<'_, K, T, W> {}
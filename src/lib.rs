@@ -28,7 +28,7 @@
 //!     Neptune = 102.0,
 //! );
 //!
-//! assert_eq!(planets_weight_10x24kg[Planets::Neptune], 102.0);
+//! assert_eq!(planets_weight_10x24kg[PlanetsKey::Neptune], 102.0);
 //! ```
 //!
 //! Use as a constant example.
@@ -50,591 +50,1915 @@
 //!     Neptune = 30.06,
 //! );
 //!
-//! for (distance, id) in PLANETS_DISTANCE_AU.iter().into_iter().cloned().zip(Planets::keys()) {
-//!     assert_eq!(PLANETS_DISTANCE_AU[id], distance);
+//! for (id, distance) in PLANETS_DISTANCE_AU.iter_entries() {
+//!     assert_eq!(&PLANETS_DISTANCE_AU[id], distance);
 //! }
 //! ```
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod iter;
+mod map;
+mod set;
+mod subset;
+mod table;
+
+pub use iter::{Entries, EntriesMut, IntoEntries, SetIter, SubsetEntries, SubsetEntriesMut};
+pub use map::ArrMap;
+pub use set::ArrSet;
+pub use subset::{Subset, SubsetMut};
+pub use table::{ArrSymTable, ArrTable};
+
 #[allow(unused)]
 #[doc(hidden)]
 pub use paste as __private_paste;
 
+/// An error which can be returned when parsing a key from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseKeyError {
+    names: &'static [&'static str],
+}
+
+impl ParseKeyError {
+    #[doc(hidden)]
+    pub const fn new(names: &'static [&'static str]) -> Self {
+        Self { names }
+    }
+
+    /// Get a list of valid key names.
+    pub const fn names(&self) -> &'static [&'static str] {
+        self.names
+    }
+}
+
+impl core::fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown key name, expected one of: ")?;
+
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseKeyError {}
+
+/// An error which can be returned when converting a list of values into a map of a different size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl LengthError {
+    const fn new(expected: usize, actual: usize) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Get the amount of values expected (amount of keys).
+    pub const fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Get the amount of values given.
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl core::fmt::Display for LengthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected {} values, but got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+/// An error which can be returned when converting a key of an extended key set into a key of its part,
+/// which doesn't have such a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingKeyError {
+    name: &'static str,
+}
+
+impl MissingKeyError {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// Get a name of the key.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl core::fmt::Display for MissingKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "key {} is missing in the key set", self.name)
+    }
+}
+
+impl core::error::Error for MissingKeyError {}
+
+/// A common interface of key types built by [`maparr`].
+///
+/// It's implemented for every generated key type,
+/// so it can be used to write a code which is generic over key sets.
+///
+/// ```
+/// use maparr::{maparr, Key};
+///
+/// fn parse_all<K: Key>(list: &str) -> Result<Vec<K>, maparr::ParseKeyError> {
+///     list.split(',').map(|name| name.trim().parse()).collect()
+/// }
+///
+/// maparr!(Planets; Mercury, Venus, Earth);
+///
+/// assert_eq!(PlanetsKey::COUNT, 3);
+/// assert_eq!(PlanetsKey::ALL, &[PlanetsKey::Mercury, PlanetsKey::Venus, PlanetsKey::Earth]);
+/// assert_eq!(parse_all::<PlanetsKey>("Earth, Venus").unwrap(), [PlanetsKey::Earth, PlanetsKey::Venus]);
+/// ```
+///
+/// # Safety
+///
+/// An implementor must be a `#[repr(usize)]` fieldless enum with discriminants `0..COUNT`,
+/// listed in [`Key::ALL`] and [`Key::NAMES`] in the discriminants order,
+/// and [`Key::index`] must return the discriminant.
+/// [`ArrMap`] relies on it to access values without bounds checks.
+pub unsafe trait Key:
+    Copy
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + core::fmt::Display
+    + core::str::FromStr<Err = ParseKeyError>
+    + 'static
+{
+    /// Amount of keys.
+    const COUNT: usize;
+
+    /// A list of all keys in declaration order.
+    ///
+    /// It's a slice rather than `[Self; Self::COUNT]` because such an array type
+    /// can't be used in a trait on stable rust, though its length is always [`Key::COUNT`].
+    const ALL: &'static [Self];
+
+    /// A list of all key names in declaration order.
+    const NAMES: &'static [&'static str];
+
+    /// Get an index of the key.
+    fn index(self) -> usize;
+
+    /// Get a key by its index, returns `None` if the index is out of bounds.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Get a name of the key.
+    fn name(self) -> &'static str;
+
+    /// Get a list of keys in a given range in declaration order,
+    /// it's empty if the range is reversed.
+    fn range<R: core::ops::RangeBounds<Self>>(range: R) -> &'static [Self] {
+        &Self::ALL[map::key_range(range)]
+    }
+}
+
+/// A marker of a key set which includes every key of `P`,
+/// it's implemented by [`maparr`] for a key type of an extended map and each of its base maps.
+///
+/// # Safety
+///
+/// `From<P>` must map distinct keys of `P` into distinct keys of the implementor,
+/// and `P::try_from` must succeed exactly for the keys it maps into, returning the original key.
+/// [`ArrMap::embed`] and [`ArrMap::project`] rely on it to move each value exactly once.
+#[doc(hidden)]
+pub unsafe trait Embeds<P: Key>: Key + From<P> {}
+
+/// A common interface of maps built by [`maparr`].
+///
+/// It's implemented for every generated map type,
+/// so it can be used to write a code which is generic over maps.
+///
+/// ```
+/// use maparr::{maparr, MapArr};
+///
+/// fn argmax<M>(map: &M) -> M::Key
+/// where
+///     M: MapArr,
+///     M::Value: PartialOrd,
+/// {
+///     let mut max = M::keys()[0];
+///     for &key in M::keys() {
+///         if map.get(key) > map.get(max) {
+///             max = key;
+///         }
+///     }
+///
+///     max
+/// }
+///
+/// maparr!(Planets; Mercury, Venus, Earth);
+///
+/// let weight = maparr!(Planets; Mercury = 0.33, Venus = 4.87, Earth = 5.97);
+/// assert_eq!(argmax(&weight), PlanetsKey::Earth);
+/// ```
+pub trait MapArr {
+    /// A key type of the map.
+    type Key: Key;
+    /// A value type of the map.
+    type Value;
+
+    /// Amount of keys.
+    const LEN: usize;
+
+    /// Get an object by an id.
+    fn get(&self, key: Self::Key) -> &Self::Value;
+
+    /// Get a mutable object by an id.
+    fn get_mut(&mut self, key: Self::Key) -> &mut Self::Value;
+
+    /// Get an list of keys supported.
+    fn keys() -> &'static [Self::Key];
+
+    /// Get an list of keys names supported.
+    fn names() -> &'static [&'static str];
+
+    /// Iterate objects.
+    fn iter(&self) -> core::slice::Iter<'_, Self::Value>;
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __private_maparr {
     (@ __check_uniq_ident $($idents:ident)*) => {
         {
-            #[allow(dead_code, non_camel_case_types)]
+            #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
             enum Idents { $($idents,)* }
         }
     };
     (@ __check_size_ident $name:ident $($idents:ident)*) => {
-        {
+        const _: () = {
             let size = $crate::__private_maparr!(@ __count_ids $($idents),*);
-            let expected = $name::len();
+            let expected = <$name as $crate::Key>::COUNT;
             if size > expected {
-                panic!(concat!("parameter list is too big"));
+                ::core::panic!("parameter list is too big");
             } else if size < expected {
-                panic!(concat!("parameter list is too small"));
+                ::core::panic!("parameter list is too small");
+            }
+        };
+    };
+    (@ __sum_ids $first:ident) => { $first };
+    (@ __sum_ids $first:ident $($rest:ident)*) => { $first + $crate::__private_maparr!(@ __sum_ids $($rest)*) };
+    (@ __count_ids $first:ident) => { 1 };
+    (@ __count_ids $first:ident, $($rest:ident),*) => { 1 + $crate::__private_maparr!(@ __count_ids $($rest),*) };
+    (@ __gen_key $publicity:vis $name:ident, $key:ident, $set:ident, $($id:ident),*) => {
+        #[doc = concat!("A key type of [`", stringify!($name), "`].")]
+        ///
+        /// It's a fieldless enum, so it can be matched exhaustively,
+        /// and it can't hold an index which is out of map bounds.
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[repr(usize)]
+        $publicity enum $key {
+            $(
+                #[doc = stringify!($id)]
+                $id,
+            )*
+        }
+
+        #[allow(unused)]
+        impl $key {
+            /// Get an index of the key.
+            pub const fn index(self) -> usize {
+                self as usize
+            }
+
+            /// Get a name of the key.
+            pub const fn name(self) -> &'static str {
+                <Self as $crate::Key>::NAMES[self as usize]
+            }
+
+            /// Parse a key by its name ignoring ASCII case.
+            pub fn from_str_ignore_case(name: &str) -> Result<Self, $crate::ParseKeyError> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($id)) {
+                        return Ok($key::$id);
+                    }
+                )*
+
+                Err($crate::ParseKeyError::new(<Self as $crate::Key>::NAMES))
+            }
+
+            /// Get a key by its index, returns `None` if the index is out of bounds.
+            pub const fn try_from_index(index: usize) -> Option<Self> {
+                let keys = <Self as $crate::Key>::ALL;
+                if index < keys.len() {
+                    Some(keys[index])
+                } else {
+                    None
+                }
+            }
+
+            /// Get the first key.
+            pub const fn first() -> Self {
+                <Self as $crate::Key>::ALL[0]
+            }
+
+            /// Get the last key.
+            pub const fn last() -> Self {
+                let keys = <Self as $crate::Key>::ALL;
+                keys[keys.len() - 1]
+            }
+
+            /// Get a following key, returns `None` if it's the last key.
+            pub const fn next(self) -> Option<Self> {
+                let keys = <Self as $crate::Key>::ALL;
+                let index = self as usize + 1;
+                if index < keys.len() {
+                    Some(keys[index])
+                } else {
+                    None
+                }
+            }
+
+            /// Get a preceding key, returns `None` if it's the first key.
+            pub const fn prev(self) -> Option<Self> {
+                match (self as usize).checked_sub(1) {
+                    Some(index) => Some(<Self as $crate::Key>::ALL[index]),
+                    None => None,
+                }
+            }
+
+            /// Get a following key, returns the first key if it's the last key.
+            pub const fn next_wrapping(self) -> Self {
+                match self.next() {
+                    Some(key) => key,
+                    None => <Self as $crate::Key>::ALL[0],
+                }
+            }
+
+            /// Get a preceding key, returns the last key if it's the first key.
+            pub const fn prev_wrapping(self) -> Self {
+                let keys = <Self as $crate::Key>::ALL;
+                match self.prev() {
+                    Some(key) => key,
+                    None => keys[keys.len() - 1],
+                }
+            }
+
+            /// Get a list of keys in a given range in declaration order,
+            /// it's empty if the range is reversed.
+            pub fn range<R: core::ops::RangeBounds<Self>>(range: R) -> &'static [Self] {
+                <Self as $crate::Key>::range(range)
+            }
+
+            /// Iterate all keys starting from the key and wrapping around at the last one.
+            pub fn iter_from(self) -> impl Iterator<Item = Self> {
+                let keys = <Self as $crate::Key>::ALL;
+                keys.iter()
+                    .copied()
+                    .cycle()
+                    .skip(self as usize)
+                    .take(keys.len())
+            }
+        }
+
+        impl From<$key> for usize {
+            fn from(id: $key) -> usize {
+                id as usize
+            }
+        }
+
+        // SAFETY: the key is a `#[repr(usize)]` fieldless enum with implicit discriminants.
+        unsafe impl $crate::Key for $key {
+            const COUNT: usize = $crate::__private_maparr!(@ __count_ids $($id),*);
+            const ALL: &'static [Self] = &[$($key::$id),*];
+            const NAMES: &'static [&'static str] = &[$(stringify!($id)),*];
+
+            fn index(self) -> usize {
+                self as usize
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                <Self as $crate::Key>::ALL.get(index).copied()
+            }
+
+            fn name(self) -> &'static str {
+                <Self as $crate::Key>::NAMES[self as usize]
+            }
+        }
+
+        impl core::fmt::Display for $key {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+
+        impl core::str::FromStr for $key {
+            type Err = $crate::ParseKeyError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $(
+                        stringify!($id) => Ok($key::$id),
+                    )*
+                    _ => Err($crate::ParseKeyError::new(<Self as $crate::Key>::NAMES)),
+                }
+            }
+        }
+
+        impl core::convert::TryFrom<&str> for $key {
+            type Error = $crate::ParseKeyError;
+
+            fn try_from(name: &str) -> Result<Self, Self::Error> {
+                name.parse()
             }
         }
+
+        #[doc = concat!("A set of [`", stringify!($key), "`] keys.")]
+        #[allow(dead_code)]
+        $publicity type $set = $crate::ArrSet<$key, { <$key as $crate::Key>::COUNT.div_ceil(64) }>;
+    };
+    (@ __gen_groups $key:ident, $set:ident, $($group:ident = [$($group_id:ident),*]),*) => {
+        impl $key {
+            $(
+                #[doc = concat!("A subset of keys: ", stringify!($($group_id),*), ".")]
+                #[allow(non_upper_case_globals)]
+                pub const $group: $set = $set::from_keys([$($key::$group_id),*]);
+            )*
+        }
     };
-    (@ __check_order_ident $name:ident $($idents:ident)*) => {
-        #[allow(unused_assignments)]
-        {
-            let mut index = 0;
+    (@ __extend_parts $name:ident [$($header:tt)*] [$($acc:ident)*] [$($id:ident),* $(,)?] $($parts:tt)*) => {
+        $crate::__private_maparr!(@ __extend_next $name [$($header)*] [$($acc)* $($id)*] $($parts)*);
+    };
+    (@ __extend_parts $name:ident [$($header:tt)*] [$($acc:ident)*] $($parts:tt)+) => {
+        $crate::__private_maparr!(@ __extend_base $name [$($header)*] [$($acc)*] [] $($parts)+);
+    };
+    (@ __extend_base $name:ident [$($header:tt)*] [$($acc:ident)*] [] :: $($parts:tt)+) => {
+        $crate::__private_maparr!(@ __extend_base $name [$($header)*] [$($acc)*] [::] $($parts)+);
+    };
+    (@ __extend_base $name:ident [$($header:tt)*] [$($acc:ident)*] [$($path:tt)*] $segment:ident :: $($parts:tt)+) => {
+        $crate::__private_maparr!(@ __extend_base $name [$($header)*] [$($acc)*] [$($path)* $segment ::] $($parts)+);
+    };
+    (@ __extend_base $name:ident [$($header:tt)*] [$($acc:ident)*] [$($path:tt)*] $base:ident [$($id:ident),* $(,)?] $($parts:tt)*) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(@ __gen_embedding $($path)* [<$base Key>], [<$name Key>], $($id)*);
+        }
+
+        $crate::__private_maparr!(@ __extend_next $name [$($header)*] [$($acc)* $($id)*] $($parts)*);
+    };
+    (@ __extend_next $name:ident [$($header:tt)*] [$($acc:ident)*] $(,)?) => {
+        $crate::__private_maparr!($($header)*; $($acc),*);
+    };
+    (@ __extend_next $name:ident [$($header:tt)*] [$($acc:ident)*] + $($parts:tt)+) => {
+        $crate::__private_maparr!(@ __extend_parts $name [$($header)*] [$($acc)*] $($parts)+);
+    };
+    (@ __gen_embedding $base:ty, $key:ident, $($id:ident)*) => {
+        unsafe impl $crate::Embeds<$base> for $key {}
+
+        impl From<$base> for $key {
+            fn from(key: $base) -> Self {
+                match key {
+                    $(<$base>::$id => $key::$id,)*
+                }
+            }
+        }
+
+        impl core::convert::TryFrom<$key> for $base {
+            type Error = $crate::MissingKeyError;
+
+            fn try_from(key: $key) -> Result<Self, Self::Error> {
+                #[allow(unreachable_patterns)]
+                match key {
+                    $($key::$id => Ok(<$base>::$id),)*
+                    _ => Err($crate::MissingKeyError::new(<$key as $crate::Key>::name(key))),
+                }
+            }
+        }
+    };
+    (@ __gen_alias [$($attrs:tt)*] #[derive($($derive:tt)*)] $($rest:tt)*) => {
+        $crate::__private_maparr!(@ __check_derive $($derive)*);
+        $crate::__private_maparr!(@ __gen_alias [$($attrs)*] $($rest)*);
+    };
+    (@ __gen_alias [$($attrs:tt)*] #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::__private_maparr!(@ __gen_alias [$($attrs)* #[$($attr)*]] $($rest)*);
+    };
+    (@ __check_derive $(,)?) => {};
+    (@ __check_derive Debug $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Clone $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Copy $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive PartialEq $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Eq $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive PartialOrd $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Ord $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Hash $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive Default $($rest:tt)*) => { $crate::__private_maparr!(@ __check_derive $($rest)*); };
+    (@ __check_derive , $($rest:tt)+) => { $crate::__private_maparr!(@ __check_derive $($rest)+); };
+    (@ __check_derive $($rest:tt)+) => {
+        core::compile_error!(concat!("derives are not supported on maparr maps: ", stringify!($($rest)+)));
+    };
+    (@ __gen_alias [$($attrs:tt)*] $($alias:tt)*) => {
+        $($attrs)*
+        #[allow(dead_code)]
+        $($alias)*
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident; $($id:ident),* $(,)? $(; $($group:ident = [$($group_id:ident),* $(,)?]),* $(,)?)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(@ __gen_key $publicity $name, [<$name Key>], [<$name Set>], $($id),*);
+            $crate::__private_maparr!(@ __gen_groups [<$name Key>], [<$name Set>], $($($group = [$($group_id),*]),*)?);
+
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small hashmap backed by an array."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T> = $crate::ArrMap<[<$name Key>], T, { <[<$name Key>] as $crate::Key>::COUNT }>;
+            );
+
+            impl [<$name Key>] {
+                #[doc(hidden)]
+                pub const fn __private_typed<T>(map: $name<T>) -> $name<T> {
+                    map
+                }
+            }
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty>; $($id:ident),* $(,)? $(; $($group:ident = [$($group_id:ident),* $(,)?]),* $(,)?)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(@ __gen_key $publicity $name, [<$name Key>], [<$name Set>], $($id),*);
+            $crate::__private_maparr!(@ __gen_groups [<$name Key>], [<$name Set>], $($($group = [$($group_id),*]),*)?);
+
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small hashmap backed by an array."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T = $name_type> = $crate::ArrMap<[<$name Key>], T, { <[<$name Key>] as $crate::Key>::COUNT }>;
+            );
+
+            impl [<$name Key>] {
+                #[doc(hidden)]
+                pub const fn __private_typed(map: $name) -> $name {
+                    map
+                }
+            }
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = symmetric $key:ident $(,)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small symmetric 2-D hashmap backed by an array, indexed by pairs of keys."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T> = $crate::ArrSymTable<[<$key Key>], T, { <[<$key Key>] as $crate::Key>::COUNT }>;
+            );
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = symmetric $key:ident $(,)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small symmetric 2-D hashmap backed by an array, indexed by pairs of keys."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T = $name_type> = $crate::ArrSymTable<[<$key Key>], T, { <[<$key Key>] as $crate::Key>::COUNT }>;
+            );
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = $row:ident * $col:ident $(,)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small 2-D hashmap backed by an array, indexed by pairs of keys."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T> = $crate::ArrTable<
+                    [<$row Key>],
+                    [<$col Key>],
+                    T,
+                    { <[<$row Key>] as $crate::Key>::COUNT },
+                    { <[<$col Key>] as $crate::Key>::COUNT },
+                >;
+            );
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = $row:ident * $col:ident $(,)?) => {
+        $crate::__private_paste::paste!{
+            $crate::__private_maparr!(
+                @ __gen_alias [#[doc = "A small 2-D hashmap backed by an array, indexed by pairs of keys."]]
+                $(#[$($derive_block)*])*
+                $publicity type $name<T = $name_type> = $crate::ArrTable<
+                    [<$row Key>],
+                    [<$col Key>],
+                    T,
+                    { <[<$row Key>] as $crate::Key>::COUNT },
+                    { <[<$col Key>] as $crate::Key>::COUNT },
+                >;
+            );
+        }
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = $($parts:tt)+) => {
+        $crate::__private_maparr!(
+            @ __extend_parts $name [$(#[$($derive_block)*])* $publicity $name] []
+            $($parts)+
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = $($parts:tt)+) => {
+        $crate::__private_maparr!(
+            @ __extend_parts $name [$(#[$($derive_block)*])* $publicity $name<$name_type>] []
+            $($parts)+
+        );
+    };
+    ( $name:ident; for $key:ident => $value:expr $(,)?) => {
+        $crate::__private_paste::paste!{
+            {
+                let mut builder = $name::__private_builder();
+                while let Some($key) = builder.next_key() {
+                    builder.push($value);
+                }
+
+                [<$name Key>]::__private_typed(builder.finish())
+            }
+        }
+    };
+    ( $name:ident; $($id:ident = $id_value:expr,)* ..Default $(,)?) => {
+        $crate::__private_maparr!($name; $($id = $id_value,)* ..$name::default())
+    };
+    ( $name:ident; $($id:ident = $id_value:expr,)* ..$base:expr $(,)?) => {
+        $crate::__private_paste::paste!{
+            {
+                $crate::__private_maparr!(@ __check_uniq_ident $($id)*);
+
+                let entries = [
+                    $(([<$name Key>]::$id, $id_value)),*
+                ];
+
+                let (map, _replaced) = $name::__private_replace_entries($base, entries);
+                [<$name Key>]::__private_typed(map)
+            }
+        }
+    };
+    ( $name:ident; $($id:ident = $id_value:expr),* $(,)?) => {
+        $crate::__private_paste::paste!{
+            {
+                $crate::__private_maparr!(@ __check_uniq_ident $($id)*);
+                $crate::__private_maparr!(@ __check_size_ident [<$name Key>] $($id)*);
+
+                let entries = [
+                    $(([<$name Key>]::$id, $id_value)),*
+                ];
+
+                // SAFETY: keys are checked to be unique and complete.
+                let map = unsafe { $name::__private_from_entries(entries) };
+                [<$name Key>]::__private_typed(map)
+            }
+        }
+    };
+}
+
+/// A macros for `declaration` and `definition` of a `maparr` type.
+///
+/// Macros has a few syntax options:
+///
+/// 1. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0, VARIANT_NAME_1, VARIANT_NAME_2)` - Define a type for map with a given set of variants as expected IDs.
+/// 2. `maparr!(STRUCTURE_NAME<TYPE_NAME>; VARIANT_NAME_0, VARIANT_NAME_1, VARIANT_NAME_2)` - Define a type for map with a given set of variants as expected IDs, compared to 1st option it specifies a default value type (`STRUCTURE_NAME<OTHER_TYPE>` is still a map with the same keys).
+/// 3. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0 = VALUE_0, VARIANT_NAME_1 = VALUE_1)` - Creates an object of a given static map, keys can be listed in any order.
+/// 4. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0 = VALUE_0, ..BASE)` - Creates an object of a given static map, taking not listed values from `BASE` map.
+/// 5. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0 = VALUE_0, ..Default)` - Creates an object of a given static map, filling not listed values with `Default::default()`.
+/// 6. `maparr!(STRUCTURE_NAME; for KEY => VALUE)` - Creates an object of a given static map, evaluating `VALUE` for each `KEY` (works in `const` context).
+/// 7. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0, VARIANT_NAME_1, VARIANT_NAME_2; GROUP_NAME = [VARIANT_NAME_0, VARIANT_NAME_2])` - Define a type for map (1st or 2nd option) together with named subsets of keys.
+/// 8. `maparr!(TABLE_NAME = ROW_STRUCTURE_NAME * COLUMN_STRUCTURE_NAME)` - Define a type for 2-D map indexed by pairs of keys of given maps, a value type can be specified as in the 2nd option (`TABLE_NAME<TYPE_NAME> = ..`).
+///    `maparr!(TABLE_NAME = symmetric STRUCTURE_NAME)` defines a square 2-D map which is kept symmetric.
+/// 9. `maparr!(STRUCTURE_NAME = BASE_STRUCTURE_NAME_0[VARIANT_NAME_0, VARIANT_NAME_1] + BASE_STRUCTURE_NAME_1[VARIANT_NAME_2] + [VARIANT_NAME_3])` - Define a type for map with listed keys of given maps (a base map can be given by a path) and given variants, a value type can be specified as in the 2nd option.
+///
+/// # Examples
+///
+/// ## Example 1
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// ```
+///
+/// ## Example 2
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map<usize>; ID1, ID2);
+/// const MAP: Map = maparr!(Map; ID1 = 1, ID2 = 3);
+/// let halves: Map<f32> = MAP.map(|&value| value as f32 / 2.0);
+/// assert_eq!(halves.as_ref(), &[0.5, 1.5]);
+/// ```
+///
+/// Values given to the macro (3-6 cases) are always of the specified type,
+/// a map with other values can be built via `Self::map` and other constructors.
+///
+/// A default type parameter is not applied in expression position,
+/// so `Map::new(..)`, `Map::from_fn(..)`, `Map::splat(..)` and `Map::default()` infer a value type from their arguments.
+/// Call them via a qualified path `<Map>::new(..)` (or annotate a type) to keep the specified one.
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map<u32>; ID1, ID2);
+/// let map = <Map>::splat(1);
+/// let _: Map<u32> = map;
+/// let map = Map::splat(1u8);
+/// let _: Map<u8> = map;
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map<u32>; ID1, ID2);
+/// let map = <Map>::new([(MapKey::ID1, 1u8), (MapKey::ID2, 2u8)]);
+/// ```
+///
+/// ## Example 3
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// let m = maparr!(Map; ID1 = 10, ID2 = 100);
+/// ```
+///
+/// ## Example 4
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2, ID3);
+/// const BASE: Map<usize> = maparr!(Map; ID1 = 10, ID2 = 100, ID3 = 1000);
+/// const MAP: Map<usize> = maparr!(Map; ID2 = 200, ..BASE);
+/// assert_eq!(MAP.as_ref(), &[10, 200, 1000]);
+/// ```
+///
+/// ## Example 5
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2, ID3);
+/// let m: Map<usize> = maparr!(Map; ID2 = 200, ..Default);
+/// assert_eq!(m.as_ref(), &[0, 200, 0]);
+/// ```
+///
+/// `..Default` can't be used in `const` context as [`Default`] is not a `const` trait,
+/// use `..BASE` with a `const` map instead.
+///
+/// ## Example 6
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2, ID3);
+/// const BASE: Map<usize> = maparr!(Map; ID1 = 10, ID2 = 100, ID3 = 1000);
+/// const MAP: Map<usize> = maparr!(Map; for id => *BASE.get(id) * 2);
+/// assert_eq!(MAP.as_ref(), &[20, 200, 2000]);
+/// ```
+///
+/// ## Example 7
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(
+///     Continents;
+///     ASIA, AFRICA, AMERICA_NORTH, AMERICA_SOUTH, ANTARCTICA, EUROPE, AUSTRALIA;
+///     AMERICAS = [AMERICA_NORTH, AMERICA_SOUTH],
+///     EURASIA = [EUROPE, ASIA],
+/// );
+///
+/// let mut population: Continents<u32> = maparr!(Continents; AMERICA_NORTH = 579, AMERICA_SOUTH = 423, ..Default);
+/// assert_eq!(population.subset(ContinentsKey::AMERICAS).sum::<u32>(), 1002);
+///
+/// for (_, value) in population.subset_mut(ContinentsKey::EURASIA) {
+///     *value = 1;
+/// }
+/// assert_eq!(population[ContinentsKey::ASIA], 1);
+/// ```
+///
+/// ## Example 8
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(Planets; Mercury, Venus, Earth);
+/// maparr!(Transfers<u32> = Planets * Planets);
+/// maparr!(Distances<u32> = symmetric Planets);
+///
+/// const MILLION_KM: Planets<u32> = maparr!(Planets; Mercury = 58, Venus = 108, Earth = 150);
+///
+/// let mut transfers = Transfers::splat(0);
+/// transfers[(PlanetsKey::Earth, PlanetsKey::Venus)] = 3;
+/// assert_eq!(transfers.row(PlanetsKey::Earth)[PlanetsKey::Venus], 3);
+/// assert!(!transfers.is_symmetric());
+///
+/// let mut distances = Distances::from_fn(|a, b| MILLION_KM[b] - MILLION_KM[a]);
+/// assert_eq!(distances[(PlanetsKey::Earth, PlanetsKey::Venus)], 42);
+/// assert_eq!(distances[(PlanetsKey::Venus, PlanetsKey::Earth)], 42);
+///
+/// distances.set(PlanetsKey::Venus, PlanetsKey::Mercury, 50);
+/// assert_eq!(distances.row(PlanetsKey::Mercury)[PlanetsKey::Venus], 50);
+/// assert!(distances.as_table().is_symmetric());
+/// ```
+///
+/// A symmetric table gives no mutable access to a single value,
+/// so it can be changed only by `set` which writes both pairs.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Planets; Mercury, Venus, Earth);
+/// maparr!(Distances<u32> = symmetric Planets);
+///
+/// let mut distances = Distances::splat(0);
+/// distances[(PlanetsKey::Earth, PlanetsKey::Venus)] = 42;
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Planets; Mercury, Venus, Earth);
+/// maparr!(Distances<u32> = symmetric Planets);
+///
+/// let mut distances = Distances::splat(0);
+/// *distances.get_mut(PlanetsKey::Earth, PlanetsKey::Venus) = 42;
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Planets; Mercury, Venus, Earth);
+/// maparr!(Distances<u32> = symmetric Planets);
+///
+/// let mut distances = Distances::splat(0);
+/// distances.row_mut(PlanetsKey::Earth)[PlanetsKey::Venus] = 42;
+/// ```
+///
+/// ## Example 9
+///
+/// ```
+/// use maparr::maparr;
+/// maparr!(BaseStats; Hp, Mp);
+/// maparr!(ExtendedStats = BaseStats[Hp, Mp] + [Armor]);
+///
+/// let base = maparr!(BaseStats; Hp = 100, Mp = 50);
+/// let extended: ExtendedStats<u32> = base.embed(|_| 0);
+/// assert_eq!(extended.as_ref(), &[100, 50, 0]);
+///
+/// let base: BaseStats<u32> = extended.with(ExtendedStatsKey::Hp, 10).project();
+/// assert_eq!(base.as_ref(), &[10, 50]);
+///
+/// assert_eq!(ExtendedStatsKey::from(BaseStatsKey::Mp), ExtendedStatsKey::Mp);
+/// assert!(BaseStatsKey::try_from(ExtendedStatsKey::Armor).is_err());
+/// ```
+///
+/// Keys of a base map are listed explicitly and checked at compile time,
+/// so a missing or an unknown key of a base map is a compilation error.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(BaseStats; Hp, Mp);
+/// maparr!(ExtendedStats = BaseStats[Hp] + [Armor]);
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(BaseStats; Hp, Mp);
+/// maparr!(ExtendedStats = BaseStats[Hp, Mp, Sp] + [Armor]);
+/// ```
+///
+/// `embed` and `project` are generated only for an extended map and its base maps,
+/// a conversion between key types written by hand is not enough.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(BaseStats; Hp, Mp);
+/// maparr!(OtherStats; Hp, Mp, Armor);
+///
+/// impl From<BaseStatsKey> for OtherStatsKey {
+///     fn from(key: BaseStatsKey) -> Self {
+///         OtherStatsKey::Armor
+///     }
+/// }
+///
+/// let other: OtherStats<u32> = maparr!(OtherStats; Hp = 1, Mp = 2, Armor = 3);
+/// let base: BaseStats<u32> = other.project();
+/// ```
+///
+/// # Compile time checks
+///
+/// The 3rd form is checked at compile time in any context,
+/// so a missing key, an unknown key or a duplicated key is a compilation error.
+/// Only the macro is checked this way, `Self::new` checks keys when it's called,
+/// so its duplicated key is a compilation error only in `const` context and a panic otherwise.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// let m = maparr!(Map; ID1 = 10);
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// let m = maparr!(Map; ID1 = 10, ID2 = 20, ID3 = 30);
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// let m = maparr!(Map; ID1 = 10, ID3 = 20);
+/// ```
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2);
+/// let m = maparr!(Map; ID1 = 10, ID1 = 20);
+/// ```
+///
+/// Keys of named subsets (7th form) are checked as well.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(Map; ID1, ID2; GROUP = [ID1, ID3]);
+/// ```
+///
+/// A derive of a trait which is not implemented by [`ArrMap`] is reported instead of being ignored.
+///
+/// ```compile_fail
+/// use maparr::maparr;
+/// maparr!(#[derive(Debug, Clone, Serialize)] Map; ID1, ID2);
+/// ```
+///
+/// # Generated api you can expect to see
+///
+/// The macro generates a key type `STRUCTURE_NAMEKey` (e.g. `PlanetsKey` for `Planets`)
+/// and a type alias `STRUCTURE_NAME` to [`ArrMap`], both have the same visibility as given.
+/// `#[derive(..)]` of traits [`ArrMap`] already implements (`Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord`, `Hash`, `Default`) are ignored, any other derive is a compilation error.
+///
+/// Every key type implements [`Key`] trait and every map implements [`MapArr`] trait.
+///
+/// The key type is a `#[repr(usize)]` enum with a variant per key, so it can be used in an exhaustive `match`.
+///
+/// - `STRUCTURE_NAMEKey::index` returns an index of a key.
+/// - `STRUCTURE_NAMEKey::try_from_index` returns a key by its index if there's such.
+/// - `STRUCTURE_NAMEKey::name` returns a name of a key, which is also used by its `Display` implementation.
+/// - `STRUCTURE_NAMEKey::from_str_ignore_case` parses a key by its name ignoring ASCII case,
+///   the case sensitive parsing is available via `FromStr` and `TryFrom<&str>`.
+/// - `STRUCTURE_NAMEKey::first` and `STRUCTURE_NAMEKey::last` return the first and the last keys.
+/// - `STRUCTURE_NAMEKey::next`, `STRUCTURE_NAMEKey::prev` and their `_wrapping` variants navigate keys in declaration order.
+/// - `STRUCTURE_NAMEKey::iter_from` iterates all keys starting from a given one, wrapping around at the end.
+/// - `STRUCTURE_NAMEKey::range` returns a slice of keys of a given range (e.g. `PlanetsKey::range(PlanetsKey::Mercury..PlanetsKey::Jupiter)`).
+/// - `STRUCTURE_NAMEKey::GROUP_NAME` is a `const` set of keys of a named subset (7th form).
+///
+/// A set of keys `STRUCTURE_NAMESet` (e.g. `PlanetsSet`) is generated as an alias to [`ArrSet`] as well.
+/// It's stored as a bit array and supports set operations (`union`, `intersection`, `difference`, `complement`),
+/// it can be built in `const` context by `STRUCTURE_NAMESet::from_keys`
+/// and converted from and into a map of `bool`s.
+///
+/// A 2-D map (8th form) is generated as an alias to [`ArrTable`] indexed by pairs of keys `(ROW_STRUCTURE_NAMEKey, COLUMN_STRUCTURE_NAMEKey)`.
+/// Its rows are maps of the column keys, columns are available as maps of references,
+/// it can be transposed and a square one can be checked by `is_symmetric`.
+/// A symmetric 2-D map is generated as an alias to [`ArrSymTable`],
+/// which has only read access and `set` writing both `(a, b)` and `(b, a)`.
+///
+/// A key type of an extended map (9th form) implements `From` for a key type of each base map listed,
+/// and a key type of each base map implements `TryFrom` for it, failing with [`MissingKeyError`].
+///
+/// The map API is defined by [`ArrMap`].
+///
+/// - `Self::new` creates a new instance of static map from entries in any order (analog of [`maparr`] as a 3rd case, but only the macro checks keys at compile time, `new` panics on a duplicated key and `Self::try_new` returns `None`).
+/// - `Self::from_fn` and `Self::try_from_fn` create a new instance evaluating a value for each key.
+/// - `Self::splat` creates a new instance with the same value for each key.
+/// - `Self::default` creates a new instance with default values.
+/// - `Self::from_array` creates a new instance from values listed in keys declaration order.
+/// - `Self::into_array` converts the map into values listed in keys declaration order.
+/// - `Self::with` returns the map with a changed value (a `const` alternative of `Self::set` for `Copy` values).
+/// - `Self::replace_with` returns the map with a changed value and the old value (works for any value in `const` context).
+/// - `Self::get` gets a value by id.
+/// - `Self::get_mut` gets a value by id.
+/// - `Self::get_many_mut` gets values by a list of distinct ids.
+/// - `Self::swap`, `Self::replace`, `Self::take` and `Self::update` modify values in place.
+/// - `Self::iter` return an iterator over values.
+/// - `Self::iter_mut` return an iterator over values.
+/// - `Self::into_iter` return an iterator over values (`&Self` and `&mut Self` can be iterated as well).
+/// - `Self::iter_entries`, `Self::iter_entries_mut` and `Self::into_entries` return iterators over keys and values.
+/// - `Self::subset` and `Self::subset_mut` return a view of values of a given set of keys.
+/// - `Self::embed` and `Self::project` convert the map into a map of an extended key set and back,
+///   they are available only for key sets related by the 9th form.
+/// - `Self::range` and `Self::range_mut` return values of a given range of keys,
+///   `Self::range_entries` and `Self::range_entries_mut` iterate them together with their keys.
+/// - `Self::map` converts each value by a given function (a value type may be changed for any map).
+/// - `Self::map_with_key` converts each value by a given function which also gets a key.
+/// - `Self::into_map` converts each value by a given function consuming the map.
+/// - `Self::map_into` converts each value via [`Into`].
+/// - `Self::try_map` converts each value by a fallible function, an error is returned together with its key.
+/// - `Self::transpose` converts a map of [`Option`]s or [`Result`]s into an [`Option`] or a [`Result`] of a map.
+/// - `Self::each_ref` and `Self::each_mut` return a map of references to values.
+/// - `Self::zip` and `Self::unzip` combine 2 maps into a map of pairs and split it back.
+/// - `Self::zip_with` combines values of 2 maps by a given function.
+/// - `Self::merge_with` merges 2 maps of the same type by a given function.
+/// - `Self::sum` returns an accamulation of values.
+/// - `Self::len` return amount of keys.
+/// - `Self::is_empty` checks whether the map is empty (has 0 keys).
+/// - `Self::keys` returns list of `ID`s.
+/// - `Self::names` returns list of `ID` names.
+///
+/// The last 4 are called on a map type (e.g. `Planets::keys()`).
+#[macro_export]
+macro_rules! maparr {
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident; $($id:ident),* $(,)? $(; $($group:ident = [$($group_id:ident),* $(,)?]),* $(,)?)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name;
+            $($id),*
+            $(; $($group = [$($group_id),*]),*)?
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty>; $($id:ident),* $(,)? $(; $($group:ident = [$($group_id:ident),* $(,)?]),* $(,)?)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name<$name_type>;
+            $($id),*
+            $(; $($group = [$($group_id),*]),*)?
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = symmetric $key:ident $(,)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name = symmetric $key
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = symmetric $key:ident $(,)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name<$name_type> = symmetric $key
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = $row:ident * $col:ident $(,)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name = $row * $col
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = $row:ident * $col:ident $(,)?) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name<$name_type> = $row * $col
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident = $($parts:tt)+) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name = $($parts)+
+        );
+    };
+    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty> = $($parts:tt)+) => {
+        $crate::__private_maparr!(
+            $(#[$($derive_block)*])*
+            $publicity
+            $name<$name_type> = $($parts)+
+        );
+    };
+    ( $name:ident; for $key:ident => $value:expr $(,)?) => {
+        $crate::__private_maparr!(
+            $name;
+            for $key => $value
+        )
+    };
+    ( $name:ident; $($id:ident = $id_value:expr,)* ..Default $(,)?) => {
+        $crate::__private_maparr!(
+            $name;
+            $($id = $id_value,)*
+            ..Default
+        )
+    };
+    ( $name:ident; $($id:ident = $id_value:expr,)* ..$base:expr $(,)?) => {
+        $crate::__private_maparr!(
+            $name;
+            $($id = $id_value,)*
+            ..$base
+        )
+    };
+    ( $name:ident; $($id:ident = $id_value:expr),* $(,)?) => {
+        $crate::__private_maparr!(
+            $name;
+            $($id = $id_value),*
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+    use std::mem;
+    use std::prelude::rust_2021::*;
+    use std::vec;
+
+    #[test]
+    fn test_maparr_generic() {
+        maparr!(Map; ID1, ID2);
+
+        let map = Map::new([(MapKey::ID1, 1), (MapKey::ID2, 2)]);
+        assert_eq!(map[MapKey::ID1], 1);
+        assert_eq!(map[MapKey::ID2], 2);
+    }
+
+    #[test]
+    fn test_maparr() {
+        maparr!(Map<usize>; ID1, ID2);
+
+        let map = Map::new([(MapKey::ID1, 1), (MapKey::ID2, 2)]);
+        assert_eq!(map[MapKey::ID1], 1);
+        assert_eq!(map[MapKey::ID2], 2);
+    }
+
+    #[test]
+    fn test_maparr_value() {
+        maparr!(Map<usize>; ID1, ID2);
+        const MAP: Map = maparr!(Map; ID1 = 1, ID2 = 2);
+
+        assert_eq!(MAP[MapKey::ID1], 1);
+        assert_eq!(MAP[MapKey::ID2], 2);
+    }
+
+    #[test]
+    fn test_maparr_generic_value() {
+        maparr!(Map; ID1, ID2);
+        const MAP: Map<bool> = maparr!(Map; ID1 = false, ID2 = true);
+
+        assert!(!MAP[MapKey::ID1]);
+        assert!(MAP[MapKey::ID2]);
+    }
+
+    #[test]
+    fn test_maparr_key_type() {
+        maparr!(Map; ID1, ID2);
+
+        fn double(map: &Map<usize>, key: MapKey) -> usize {
+            map[key] * 2
+        }
+
+        let map = maparr!(Map; ID1 = 1, ID2 = 2);
+        let keys: [MapKey; 2] = Map::keys();
+        assert_eq!(double(&map, keys[1]), 4);
+        assert_eq!(usize::from(MapKey::ID2), 1);
+    }
+
+    #[test]
+    fn test_maparr_key_match() {
+        maparr!(Map<u8>; ID1, ID2);
+
+        fn describe(key: MapKey) -> &'static str {
+            match key {
+                MapKey::ID1 => "first",
+                MapKey::ID2 => "second",
+            }
+        }
+
+        assert_eq!(describe(MapKey::ID1), "first");
+        assert_eq!(describe(MapKey::ID2), "second");
+    }
+
+    #[test]
+    fn test_maparr_key_index() {
+        maparr!(Map; ID1, ID2);
+
+        assert_eq!(MapKey::try_from_index(0), Some(MapKey::ID1));
+        assert_eq!(MapKey::try_from_index(1), Some(MapKey::ID2));
+        assert_eq!(MapKey::try_from_index(2), None);
+        assert_eq!(MapKey::ID2.index(), 1);
+    }
+
+    #[test]
+    fn test_maparr_key_parse() {
+        maparr!(Map; Mercury, Venus, Earth);
+
+        assert_eq!(MapKey::Earth.to_string(), "Earth");
+        assert_eq!(format!("{:>7}", MapKey::Venus), "  Venus");
+        assert_eq!("Earth".parse::<MapKey>(), Ok(MapKey::Earth));
+        assert_eq!(MapKey::try_from("Venus"), Ok(MapKey::Venus));
+        assert_eq!(MapKey::from_str_ignore_case("mercury"), Ok(MapKey::Mercury));
+
+        let err = "earth".parse::<MapKey>().unwrap_err();
+        assert_eq!(err.names(), &["Mercury", "Venus", "Earth"]);
+        assert_eq!(
+            err.to_string(),
+            "unknown key name, expected one of: Mercury, Venus, Earth"
+        );
+    }
+
+    #[test]
+    fn test_maparr_key_reserved_names() {
+        maparr!(Buttons; KEYS, NAMES, ALL, COUNT, MOUSE);
+
+        assert_eq!(ButtonsKey::KEYS.name(), "KEYS");
+        assert_eq!(ButtonsKey::NAMES.index(), 1);
+        assert_eq!(ButtonsKey::last(), ButtonsKey::MOUSE);
+        assert_eq!(ButtonsKey::try_from_index(3), Some(ButtonsKey::COUNT));
+        assert_eq!("NAMES".parse::<ButtonsKey>(), Ok(ButtonsKey::NAMES));
+        assert_eq!(Buttons::names(), ["KEYS", "NAMES", "ALL", "COUNT", "MOUSE"]);
+
+        let map = maparr!(Buttons; KEYS = 1, NAMES = 2, ALL = 3, COUNT = 4, MOUSE = 5);
+        assert_eq!(map[ButtonsKey::NAMES], 2);
+
+        maparr!(Pos; first, second, last, try_from_index);
+
+        assert_eq!(PosKey::first.next_wrapping(), PosKey::second);
+        assert_eq!(PosKey::last.next(), Some(PosKey::try_from_index));
+        assert_eq!(PosKey::try_from_index.next_wrapping(), PosKey::first);
+        assert_eq!(PosKey::second.prev(), Some(PosKey::first));
+        assert_eq!(<PosKey as crate::Key>::from_index(2), Some(PosKey::last));
+        assert_eq!(PosKey::first.prev_wrapping(), PosKey::try_from_index);
+    }
+
+    #[test]
+    fn test_maparr_key_navigation() {
+        maparr!(Map; ID1, ID2, ID3);
+
+        assert_eq!(MapKey::first(), MapKey::ID1);
+        assert_eq!(MapKey::last(), MapKey::ID3);
+        assert_eq!(MapKey::ID1.next(), Some(MapKey::ID2));
+        assert_eq!(MapKey::ID3.next(), None);
+        assert_eq!(MapKey::ID2.prev(), Some(MapKey::ID1));
+        assert_eq!(MapKey::ID1.prev(), None);
+        assert_eq!(MapKey::ID3.next_wrapping(), MapKey::ID1);
+        assert_eq!(MapKey::ID1.prev_wrapping(), MapKey::ID3);
+
+        let keys = MapKey::ID2.iter_from().collect::<Vec<_>>();
+        assert_eq!(keys, vec![MapKey::ID2, MapKey::ID3, MapKey::ID1]);
+    }
+
+    #[test]
+    fn test_maparr_trait() {
+        use crate::MapArr;
+
+        fn total<M: MapArr<Value = usize>>(map: &M) -> usize {
+            M::keys().iter().map(|&key| *map.get(key)).sum()
+        }
+
+        maparr!(Map1; ID1, ID2);
+        maparr!(Map2<usize>; ID1, ID2, ID3);
+
+        let mut map1 = maparr!(Map1; ID1 = 1, ID2 = 2);
+        let map2 = maparr!(Map2; ID1 = 1, ID2 = 2, ID3 = 3);
+
+        assert_eq!(total(&map1), 3);
+        assert_eq!(total(&map2), 6);
+        assert_eq!(<Map1<usize> as MapArr>::LEN, 2);
+        assert_eq!(<Map2 as MapArr>::names(), &["ID1", "ID2", "ID3"]);
+        assert_eq!(Map1::keys(), [Map1Key::ID1, Map1Key::ID2]);
+
+        *MapArr::get_mut(&mut map1, Map1Key::ID2) = 3;
+        assert_eq!(MapArr::iter(&map1).collect::<Vec<_>>(), vec![&1, &3]);
+    }
+
+    #[test]
+    fn test_maparr_key_trait() {
+        use crate::Key;
+
+        fn histogram<K: Key>(keys: &[K]) -> Vec<(&'static str, usize)> {
+            let mut counts = vec![0; K::COUNT];
+            for key in keys {
+                counts[key.index()] += 1;
+            }
+
+            K::ALL.iter().map(|key| key.name()).zip(counts).collect()
+        }
+
+        maparr!(Map; ID1, ID2, ID3);
+
+        let keys = [MapKey::ID3, MapKey::ID1, MapKey::ID3];
+        assert_eq!(histogram(&keys), vec![("ID1", 1), ("ID2", 0), ("ID3", 2)]);
+        assert_eq!(<MapKey as Key>::from_index(2), Some(MapKey::ID3));
+        assert_eq!(<MapKey as Key>::from_index(3), None);
+    }
+
+    #[test]
+    fn test_maparr_value_any_order() {
+        maparr!(Map; ID1, ID2, ID3);
+        const MAP: Map<usize> = maparr!(Map; ID3 = 3, ID1 = 1, ID2 = 2);
+
+        assert_eq!(MAP.as_ref(), &[1, 2, 3]);
+
+        let map =
+            maparr!(Map; ID2 = String::from("2"), ID3 = String::from("3"), ID1 = String::from("1"));
+        assert_eq!(map.as_ref(), &["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_maparr_value_spread() {
+        maparr!(Map; ID1, ID2, ID3);
+        const BASE: Map<usize> = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 3);
+        const MAP: Map<usize> = maparr!(Map; ID3 = 30, ID1 = 10, ..BASE);
+
+        assert_eq!(MAP.as_ref(), &[10, 2, 30]);
+
+        const TRAILING: Map<usize> = maparr!(Map; ID2 = 20, ..BASE,);
+        assert_eq!(TRAILING.as_ref(), &[1, 20, 3]);
+
+        let base =
+            maparr!(Map; ID1 = String::from("1"), ID2 = String::from("2"), ID3 = String::from("3"));
+        let map = maparr!(Map; ID2 = String::from("20"), ..base);
+        assert_eq!(map.as_ref(), &["1", "20", "3"]);
+
+        let map: Map<String> = maparr!(Map; ID2 = String::from("20"), ..Default);
+        assert_eq!(map.as_ref(), &["", "20", ""]);
+
+        let map: Map<u8> = maparr!(Map; ..Default);
+        assert_eq!(map.as_ref(), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_maparr_value_for() {
+        maparr!(Map; ID1, ID2, ID3);
+        const BASE: Map<usize> = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 3);
+        const MAP: Map<usize> = maparr!(Map; for id => *BASE.get(id) + id.index());
+
+        assert_eq!(MAP.as_ref(), &[1, 3, 5]);
+
+        let map = maparr!(Map; for id => id.to_string());
+        assert_eq!(map.as_ref(), &["ID1", "ID2", "ID3"]);
+    }
+
+    #[test]
+    fn test_maparr_const_api() {
+        maparr!(Map; ID1, ID2, ID3);
+
+        const fn build() -> Map<usize> {
+            let mut map = Map::new([(MapKey::ID1, 1), (MapKey::ID2, 2), (MapKey::ID3, 3)]);
+            *map.get_mut(MapKey::ID1) = 10;
+            map.with(MapKey::ID2, 20)
+        }
+
+        const MAP: Map<usize> = build();
+        const ARRAY: [usize; 3] = MAP.with(MapKey::ID3, 30).into_array();
+        const FROM_ARRAY: Map<usize> = Map::from_array(ARRAY);
+
+        assert_eq!(MAP.as_ref(), &[10, 20, 3]);
+        assert_eq!(ARRAY, [10, 20, 30]);
+        assert_eq!(FROM_ARRAY.as_ref(), &[10, 20, 30]);
+
+        let map = Map::from_array([String::from("1"), String::from("2"), String::from("3")]);
+        assert_eq!(map.into_array(), ["1", "2", "3"]);
+
+        #[derive(Debug, PartialEq)]
+        struct Label(&'static str);
+
+        const LABELS: (Map<Label>, Label) = Map::from_array([Label("a"), Label("b"), Label("c")])
+            .replace_with(MapKey::ID2, Label("x"));
+        assert_eq!(LABELS.0.as_ref(), &[Label("a"), Label("x"), Label("c")]);
+        assert_eq!(LABELS.1, Label("b"));
+    }
+
+    #[test]
+    fn test_maparr_new_any_order() {
+        maparr!(Map; ID1, ID2, ID3);
+
+        const MAP: Map<usize> = Map::new([(MapKey::ID3, 3), (MapKey::ID1, 1), (MapKey::ID2, 2)]);
+        assert_eq!(MAP.as_ref(), &[1, 2, 3]);
+
+        let map = Map::new([
+            (MapKey::ID2, String::from("2")),
+            (MapKey::ID3, String::from("3")),
+            (MapKey::ID1, String::from("1")),
+        ]);
+        assert_eq!(map.as_ref(), &["1", "2", "3"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_maparr_new_duplicate() {
+        maparr!(Map; ID1, ID2);
+        let _map = Map::new([(MapKey::ID2, 2), (MapKey::ID2, 1)]);
+    }
 
-            $(
-                let ident_value = $name::$idents.0;
-                if ident_value != index {
-                    panic!(concat!("parameter position", "<", stringify!($idents), ">", " does not correspond to it's original position "));
-                }
+    #[test]
+    fn test_maparr_try_new() {
+        maparr!(Map; ID1, ID2);
 
-                index += 1;
-            )*
-        }
-    };
-    (@ __sum_ids $first:ident) => { $first };
-    (@ __sum_ids $first:ident $($rest:ident)*) => { $first + $crate::__private_maparr!(@ __sum_ids $($rest)*) };
-    (@ __count_ids $first:ident) => { 1 };
-    (@ __count_ids $first:ident, $($rest:ident),*) => { 1 + $crate::__private_maparr!(@ __count_ids $($rest),*) };
-    (@ __gen_property $struct_name:path, $index:expr, $first:ident) => {
-        /// ID
-        #[doc = stringify!($first)]
-        pub const $first: $struct_name = $struct_name($index);
-    };
-    (@ __gen_property $struct_name:path, $index:expr, $first:ident, $($rest:ident),*) => {
-        /// ID
-        #[doc = stringify!($first)]
-       pub const $first: $struct_name = $struct_name($index);
+        let map = Map::try_new([(MapKey::ID2, 2), (MapKey::ID1, 1)]);
+        assert_eq!(map, Some(Map::from_array([1, 2])));
+        assert_eq!(Map::try_new([(MapKey::ID2, 2), (MapKey::ID2, 1)]), None);
+    }
 
-       $crate::__private_maparr!(@ __gen_property $struct_name, $index + 1, $($rest),*);
-    };
-    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident; $($id:ident),* $(,)?) => {
-        $crate::__private_paste::paste!{
-            /// A small hashmap backed by an array.
-            $(
-                #[$($derive_block)*]
-            )*
-            $publicity struct $name<T> {
-                // note: can we hide the field somehow?
-                list: [T; [<__private_size_ $name>]::SIZE],
-            }
+    #[test]
+    fn test_maparr_constructors() {
+        use crate::LengthError;
 
-            #[doc(hidden)]
-            #[allow(non_snake_case)]
-            mod [<__private_size_ $name>] {
-                pub(super) const SIZE: usize = $crate::__private_maparr!(@ __count_ids $($id),*);
-            }
+        maparr!(Map; ID1, ID2, ID3);
 
-            #[doc(hidden)]
-            #[allow(non_snake_case)]
-            mod [<__private_id_ $name>] {
-                /// An ID type.
-                #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-                pub struct ID(pub usize);
+        let map = Map::from_fn(|key| key.index() * 10);
+        assert_eq!(map.as_ref(), &[0, 10, 20]);
 
-                impl From<ID> for usize {
-                    fn from(id: ID) -> usize {
-                        id.0
-                    }
-                }
-            }
+        let map = Map::try_from_fn(|key| key.name().parse::<u8>());
+        assert!(map.is_err());
 
-            impl $name<()> {
-                $crate::__private_maparr!(@ __gen_property [<__private_id_ $name>]::ID, 0, $($id),*);
-            }
+        let map = Map::<u8>::try_from_fn(|key| Ok::<_, ()>(key.index() as u8));
+        assert_eq!(map.unwrap().as_ref(), &[0, 1, 2]);
 
-            #[allow(unused)]
-            impl<T> $name<T> {
-                /// Creates a new structure.
-                #[allow(non_snake_case)]
-                pub fn new(
-                    $(
-                        $id: ([<__private_id_ $name>]::ID, T)
-                    ),*
-                ) -> Self {
-                    $(
-                        if $id.0.0 != $name::$id.0 {
-                            panic!(
-                                concat!(
-                                    "parameter ", "<", stringify!($id), ">",
-                                    " does not correspond to it's value id, expected it being ",
-                                    "<", stringify!($name), "::", stringify!($id), ">",
-                                )
-                            );
-                        }
-                    )*
+        let map = Map::splat(String::from("1"));
+        assert_eq!(map.as_ref(), &["1", "1", "1"]);
 
-                    let list = [
-                        $(
-                            $id.1
-                        ),*
-                    ];
+        let map = Map::<bool>::default();
+        assert_eq!(map.as_ref(), &[false, false, false]);
 
-                    Self {
-                        list,
-                    }
-                }
-            }
+        let map = Map::from([1, 2, 3]);
+        assert_eq!(map.as_ref(), &[1, 2, 3]);
 
-            #[allow(unused)]
-            impl<T> $name<T> {
-                /// Get an object by an id.
-                pub const fn get(&self, id: [<__private_id_ $name>]::ID) -> &T {
-                    &self.list[id.0]
-                }
+        let map = Map::try_from(&[1, 2, 3][..]);
+        assert_eq!(map.unwrap().as_ref(), &[1, 2, 3]);
 
-                /// Get a mutable object by an id.
-                pub fn get_mut(&mut self, id: [<__private_id_ $name>]::ID) -> &mut T {
-                    &mut self.list[id.0]
-                }
+        let map = Map::<i32>::try_from(&[1, 2][..]);
+        assert_eq!(map.unwrap_err(), LengthError::new(3, 2));
+    }
 
-                /// Set an object by an id.
-                pub fn set(&mut self, id: [<__private_id_ $name>]::ID, value: T) {
-                    self.list[id.0] = value
-                }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_maparr_constructors_vec() {
+        maparr!(Map; ID1, ID2, ID3);
 
-                /// Iterate objects.
-                pub fn iter(&self) -> impl IntoIterator<Item=&T> {
-                    self.list.iter()
-                }
+        let map = Map::try_from(vec![1, 2, 3]);
+        assert_eq!(map.unwrap().as_ref(), &[1, 2, 3]);
 
-                /// Iterate objects mutable.
-                pub fn iter_mut(&mut self) -> impl IntoIterator<Item=&mut T> {
-                    self.list.iter_mut()
-                }
+        let err = Map::<i32>::try_from(vec![1, 2, 3, 4]).unwrap_err();
+        assert_eq!((err.expected(), err.actual()), (3, 4));
+        assert_eq!(err.to_string(), "expected 3 values, but got 4");
+    }
 
-                /// Map values to new ones.
-                pub fn map<R, F>(&self, func: F) -> $name<R>
-                where
-                    F: Fn(&T) -> R,
-                {
-                    $name {
-                        list: [
-                            $(
-                                {
-                                    let value = self.get($name::$id);
-                                    func(value)
-                                }
-                            ),*
-                        ]
-                    }
-                }
+    #[test]
+    fn test_maparr_iterators() {
+        maparr!(Map; ID1, ID2, ID3);
 
-                /// Sums values together.
-                pub fn sum<'a, R>(&'a self) -> R
-                where
-                    R: core::iter::Sum<&'a T>
-                {
-                    self.list.iter().sum()
-                }
-            }
+        let mut map = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 3);
 
-            #[allow(unused)]
-            impl $name<()> {
-                /// Get a map size.
-                pub const fn len() -> usize {
-                    [<__private_size_ $name>]::SIZE
-                }
+        assert_eq!(map.iter().len(), 3);
+        assert_eq!(map.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
 
-                /// Check whether the map is empty.
-                pub const fn is_empty() -> bool {
-                    [<__private_size_ $name>]::SIZE == 0
-                }
+        for value in &mut map {
+            *value *= 10;
+        }
 
-                /// Get an list of keys supported.
-                pub const fn keys() -> [[<__private_id_ $name>]::ID; [<__private_size_ $name>]::SIZE] {
-                    [
-                        $(
-                            $name::$id
-                        ),*
-                    ]
-                }
+        let mut values = vec![];
+        for value in &map {
+            values.push(*value);
+        }
+        assert_eq!(values, vec![10, 20, 30]);
 
-                /// Get an list of keys names supported.
-                pub const fn names() -> [&'static str; [<__private_size_ $name>]::SIZE] {
-                    [
-                        $(
-                            stringify!($id)
-                        ),*
-                    ]
-                }
-            }
+        let entries = map.iter_entries().collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![(MapKey::ID1, &10), (MapKey::ID2, &20), (MapKey::ID3, &30)]
+        );
+        assert_eq!(map.iter_entries().next_back(), Some((MapKey::ID3, &30)));
+        assert_eq!(map.iter_entries().len(), 3);
 
-            impl<T> core::ops::Index<[<__private_id_ $name>]::ID> for $name<T> {
-                type Output = T;
+        for (key, value) in map.iter_entries_mut() {
+            *value += key.index();
+        }
 
-                fn index(&self, index: [<__private_id_ $name>]::ID) -> &Self::Output {
-                    self.get(index)
-                }
-            }
+        let entries = map.into_entries().collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![(MapKey::ID1, 10), (MapKey::ID2, 21), (MapKey::ID3, 32)]
+        );
+    }
 
-            impl<T> core::ops::IndexMut<[<__private_id_ $name>]::ID> for $name<T> {
-                fn index_mut(&mut self, index: [<__private_id_ $name>]::ID) -> &mut Self::Output {
-                    self.get_mut(index)
-                }
-            }
+    #[test]
+    fn test_maparr_map() {
+        maparr!(Map; ID1, ID2, ID3);
 
-            impl<T> From<$name<T>> for [T; [<__private_size_ $name>]::SIZE] {
-                fn from(map: $name<T>) -> [T; [<__private_size_ $name>]::SIZE] {
-                    map.list
-                }
-            }
+        let mut map = maparr!(Map; ID1 = 1u8, ID2 = 2, ID3 = 3);
 
-            impl<T> core::convert::AsRef<[T]> for $name<T> {
-                fn as_ref(&self) -> &[T] {
-                    &self.list
-                }
-            }
+        let mut total = 0;
+        let sums = map.map(|value| {
+            total += value;
+            total
+        });
+        assert_eq!(sums.as_ref(), &[1, 3, 6]);
 
-            impl<T> core::iter::IntoIterator for $name<T> {
-                type Item = T;
-                type IntoIter = core::array::IntoIter<Self::Item, { [<__private_size_ $name>]::SIZE }>;
+        let named = map.map_with_key(|key, value| format!("{key}={value}"));
+        assert_eq!(named.as_ref(), &["ID1=1", "ID2=2", "ID3=3"]);
 
-                fn into_iter(self) -> Self::IntoIter {
-                    self.list.into_iter()
-                }
-            }
-        }
-    };
+        let lens = named.into_map(|value| value.len());
+        assert_eq!(lens.as_ref(), &[5, 5, 5]);
 
-    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty>; $($id:ident),* $(,)?) => {
-        $crate::__private_paste::paste!{
-            /// A small hashmap backed by an array.
-            $(
-                #[$($derive_block)*]
-            )*
-            $publicity struct $name {
-                list: [$name_type; [<__private_size_ $name>]::SIZE],
-            }
+        let wide: Map<u32> = map.map_into();
+        assert_eq!(wide.as_ref(), &[1, 2, 3]);
 
-            #[doc(hidden)]
-            #[allow(non_snake_case)]
-            mod [<__private_size_ $name>] {
-                pub(super) const SIZE: usize = $crate::__private_maparr!(@ __count_ids $($id),*);
-            }
+        let refs: Map<&u8> = map.each_ref();
+        assert_eq!(*refs[MapKey::ID2], 2);
 
-            #[doc(hidden)]
-            #[allow(non_snake_case)]
-            mod [<__private_id_ $name>] {
-                /// An ID type.
-                #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-                pub struct ID(pub usize);
+        let mut refs: Map<&mut u8> = map.each_mut();
+        *refs[MapKey::ID2] = 20;
+        assert_eq!(map[MapKey::ID2], 20);
+    }
 
-                impl From<ID> for usize {
-                    fn from(id: ID) -> usize {
-                        id.0
-                    }
-                }
-            }
+    #[test]
+    fn test_maparr_map_fixed() {
+        maparr!(Map<usize>; ID1, ID2, ID3);
+        const MAP: Map = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 5);
+
+        let total = MAP.sum::<usize>() as f32;
+        let shares: Map<f32> = MAP.map(|&value| value as f32 / total);
+        assert_eq!(shares[MapKey::ID3], 0.625);
+
+        let names: [String; 3] = MAP
+            .map_with_key(|key, value| format!("{key}={value}"))
+            .into_array();
+        assert_eq!(names, ["ID1=1", "ID2=2", "ID3=5"]);
+
+        let map = <Map>::splat(1);
+        assert_eq!(mem::size_of_val(&map), mem::size_of::<[usize; 3]>());
+
+        let map = Map::splat(1u8);
+        assert_eq!(mem::size_of_val(&map), mem::size_of::<[u8; 3]>());
+
+        let map = <Map>::new([(MapKey::ID1, 1), (MapKey::ID2, 2), (MapKey::ID3, 3)]);
+        let map: Map = map.map(|value| value * 2);
+        assert_eq!(map.as_ref(), &[2, 4, 6]);
+        assert_eq!(Map::keys(), [MapKey::ID1, MapKey::ID2, MapKey::ID3]);
+        assert_eq!(Map::names(), ["ID1", "ID2", "ID3"]);
+        assert_eq!(Map::len(), 3);
+        assert!(!Map::is_empty());
+    }
 
-            impl $name {
-                $crate::__private_maparr!(@ __gen_property [<__private_id_ $name>]::ID, 0, $($id),*);
-            }
+    #[test]
+    fn test_maparr_zip() {
+        maparr!(Map; ID1, ID2, ID3);
 
-            #[allow(unused)]
-            impl $name {
-                /// Creates a new structure.
-                #[allow(non_snake_case)]
-                pub fn new(
-                    $(
-                        $id: ([<__private_id_ $name>]::ID, $name_type)
-                    ),*
-                ) -> Self {
-                    $(
-                        if $id.0.0 != $name::$id.0 {
-                            panic!(
-                                concat!(
-                                    "parameter ", "<", stringify!($id), ">",
-                                    " does not correspond to it's value id, expected it being ",
-                                    "<", stringify!($name), "::", stringify!($id), ">",
-                                )
-                            );
-                        }
-                    )*
+        let mass = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 3);
+        let distance = maparr!(Map; ID1 = 10, ID2 = 20, ID3 = 30);
 
-                    let list = [
-                        $(
-                            $id.1
-                        ),*
-                    ];
+        let pairs = mass.zip(distance);
+        assert_eq!(pairs.as_ref(), &[(1, 10), (2, 20), (3, 30)]);
 
-                    Self {
-                        list,
-                    }
-                }
-            }
+        let (left, right) = pairs.unzip();
+        assert_eq!((left, right), (mass, distance));
 
-            #[allow(unused)]
-            impl $name {
-                /// Get an object by an id.
-                pub const fn get(&self, id: [<__private_id_ $name>]::ID) -> & $name_type {
-                    &self.list[id.0]
-                }
+        let products = mass.zip_with(&distance, |key, m, d| m * d + key.index());
+        assert_eq!(products.as_ref(), &[10, 41, 92]);
 
-                /// Get a mutable object by an id.
-                pub fn get_mut(&mut self, id: [<__private_id_ $name>]::ID) -> &mut $name_type {
-                    &mut self.list[id.0]
-                }
+        let merged = mass.merge_with(distance, |key, m, d| if key == MapKey::ID2 { m } else { d });
+        assert_eq!(merged.as_ref(), &[10, 2, 30]);
 
-                /// Set an object by an id.
-                pub fn set(&mut self, id: [<__private_id_ $name>]::ID, value: $name_type) {
-                    self.list[id.0] = value
-                }
+        let names = maparr!(Map; for id => String::from(id.name()));
+        let (names, lens) = names
+            .into_map(|name| (name.to_lowercase(), name.len()))
+            .unzip();
+        assert_eq!(names.as_ref(), &["id1", "id2", "id3"]);
+        assert_eq!(lens.as_ref(), &[3, 3, 3]);
+    }
 
-                /// Iterate objects.
-                pub fn iter(&self) -> impl IntoIterator<Item=& $name_type> {
-                    self.list.iter()
-                }
+    #[test]
+    fn test_maparr_try_map() {
+        use core::num::ParseIntError;
 
-                /// Iterate objects mutable.
-                pub fn iter_mut(&mut self) -> impl IntoIterator<Item=&mut $name_type> {
-                    self.list.iter_mut()
-                }
+        maparr!(Map; ID1, ID2, ID3);
 
-                /// Map values to new ones.
-                pub fn map<F>(&self, func: F) -> $name
-                where
-                    F: Fn(&$name_type) -> $name_type,
-                {
-                    $name {
-                        list: [
-                            $(
-                                {
-                                    let value = self.get($name::$id);
-                                    func(value)
-                                }
-                            ),*
-                        ]
-                    }
-                }
+        let map = maparr!(Map; ID1 = "1", ID2 = "2", ID3 = "3");
+        let parsed = map.try_map(|_, value| value.parse::<u8>());
+        assert_eq!(parsed.unwrap().as_ref(), &[1, 2, 3]);
 
-                /// Sums values together.
-                pub fn sum<'a, R>(&'a self) -> R
-                where
-                    R: core::iter::Sum<&'a $name_type>
-                {
-                    self.list.iter().sum()
-                }
-            }
+        let map = maparr!(Map; ID1 = "1", ID2 = "x", ID3 = "");
+        let (key, _err) = map.try_map(|_, value| value.parse::<u8>()).unwrap_err();
+        assert_eq!(key, MapKey::ID2);
 
-            #[allow(unused)]
-            impl $name {
-                /// Get a map size.
-                pub const fn len() -> usize {
-                    [<__private_size_ $name>]::SIZE
-                }
+        let results: Map<Result<u8, ParseIntError>> = map.map(|value| value.parse());
+        assert_eq!(results.transpose().unwrap_err().0, MapKey::ID2);
 
-                /// Check whether the map is empty.
-                pub const fn is_empty() -> bool {
-                    [<__private_size_ $name>]::SIZE == 0
-                }
+        let results: Map<Result<u8, ParseIntError>> = maparr!(Map; for id => Ok(id.index() as u8));
+        assert_eq!(results.transpose().unwrap().as_ref(), &[0, 1, 2]);
 
-                /// Get an list of keys supported.
-                pub const fn keys() -> [[<__private_id_ $name>]::ID; [<__private_size_ $name>]::SIZE] {
-                    [
-                        $(
-                            $name::$id
-                        ),*
-                    ]
-                }
+        let options = maparr!(Map; ID1 = Some(1), ID2 = Some(2), ID3 = Some(3));
+        assert_eq!(options.transpose().unwrap().as_ref(), &[1, 2, 3]);
 
-                /// Get an list of keys names supported.
-                pub const fn names() -> [&'static str; [<__private_size_ $name>]::SIZE] {
-                    [
-                        $(
-                            stringify!($id)
-                        ),*
-                    ]
-                }
-            }
+        let options = maparr!(Map; ID1 = Some(1), ID2 = None, ID3 = Some(3));
+        assert_eq!(options.transpose(), None);
+    }
 
-            impl core::ops::Index<[<__private_id_ $name>]::ID> for $name {
-                type Output = $name_type;
+    #[test]
+    fn test_maparr_many_mut() {
+        maparr!(Map; ASIA, EUROPE, AFRICA);
 
-                fn index(&self, index: [<__private_id_ $name>]::ID) -> &Self::Output {
-                    self.get(index)
-                }
-            }
+        let mut map = maparr!(Map; ASIA = 10, EUROPE = 20, AFRICA = 30);
 
-            impl core::ops::IndexMut<[<__private_id_ $name>]::ID> for $name {
-                fn index_mut(&mut self, index: [<__private_id_ $name>]::ID) -> &mut Self::Output {
-                    self.get_mut(index)
-                }
-            }
+        let [asia, europe] = map.get_many_mut([MapKey::ASIA, MapKey::EUROPE]).unwrap();
+        *asia -= 5;
+        *europe += 5;
+        assert_eq!(map.as_ref(), &[5, 25, 30]);
 
-            impl From<$name> for [$name_type; [<__private_size_ $name>]::SIZE] {
-                fn from(map: $name) -> [$name_type; [<__private_size_ $name>]::SIZE] {
-                    map.list
-                }
-            }
+        assert!(map.get_many_mut([MapKey::ASIA, MapKey::ASIA]).is_none());
 
-            impl core::convert::AsRef<[$name_type]> for $name {
-                fn as_ref(&self) -> &[$name_type] {
-                    &self.list
-                }
-            }
+        map.swap(MapKey::ASIA, MapKey::AFRICA);
+        assert_eq!(map.as_ref(), &[30, 25, 5]);
 
-            impl core::iter::IntoIterator for $name {
-                type Item = $name_type;
-                type IntoIter = core::array::IntoIter<Self::Item, { [<__private_size_ $name>]::SIZE }>;
+        assert_eq!(map.replace(MapKey::EUROPE, 1), 25);
+        assert_eq!(map.take(MapKey::ASIA), 30);
+        assert_eq!(map.as_ref(), &[0, 1, 5]);
 
-                fn into_iter(self) -> Self::IntoIter {
-                    self.list.into_iter()
-                }
-            }
-        }
-    };
-    ( $name:ident; $($id:ident = $id_value:expr),* $(,)?) => {
-        {
-            $crate::__private_maparr!(@ __check_uniq_ident $($id)*);
-            $crate::__private_maparr!(@ __check_order_ident $name $($id)*);
-            $crate::__private_maparr!(@ __check_size_ident $name $($id)*);
-
-            $name {
-                list: [
-                    $($id_value),*
-                ],
-            }
-        }
-    };
-}
+        let old = map.update(MapKey::AFRICA, |value| mem::replace(value, *value * 2));
+        assert_eq!(old, 5);
+        assert_eq!(map.as_ref(), &[0, 1, 10]);
+    }
 
-/// A macros for `declaration` and `definition` of a `maparr` type.
-///
-/// Macros has a few syntax options:
-///
-/// 1. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0, VARIANT_NAME_1, VARIANT_NAME_2)` - Define a type for map with a given set of variants as expected IDs.
-/// 2. `maparr!(STRUCTURE_NAME<TYPE_NAME>; VARIANT_NAME_0, VARIANT_NAME_1, VARIANT_NAME_2)` - Define a type for map with a given set of variants as expected IDs, compared to 1st option it specifies a value type.
-/// 3. `maparr!(STRUCTURE_NAME; VARIANT_NAME_0 = VALUE_0, VARIANT_NAME_1 = VALUE_1)` - Creates an object of a given static map.
-///
-/// # Examples
-///
-/// ## Example 1
-///
-/// ```
-/// use maparr::maparr;
-/// maparr!(Map; ID1, ID2);
-/// ```
-///
-/// ## Example 2
-///
-/// ```
-/// use maparr::maparr;
-/// maparr!(Map<usize>; ID1, ID2);
-/// ```
-///
-/// ## Example 3
-///
-/// ```
-/// use maparr::maparr;
-/// maparr!(Map; ID1, ID2);
-/// let m = maparr!(Map; ID1 = 10, ID2 = 100);
-/// ```
-///
-/// # Generated api you can expect to see
-///
-/// - `Self::new` creates a new instance of static map (analog of [`maparr`] as a 3rd case, but macro can be used in const context).
-/// - `Self::get` gets a value by id.
-/// - `Self::get_mut` gets a value by id.
-/// - `Self::iter` return an iterator over values.
-/// - `Self::iter_mut` return an iterator over values.
-/// - `Self::into_iter` return an iterator over values.
-/// - `Self::map` converts each value by a given function.
-/// - `Self::sum` returns an accamulation of values.
-/// - `Self::len` return amount of keys.
-/// - `Self::is_empty` checks whether the map is empty (has 0 keys).
-/// - `Self::keys` returns list of `ID`s.
-/// - `Self::names` returns list of `ID` names.
-#[macro_export]
-macro_rules! maparr {
-    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident; $($id:ident),* $(,)?) => {
-        $crate::__private_maparr!(
-            $(#[$($derive_block)*])*
-            $publicity
-            $name;
-            $($id),*
+    #[test]
+    fn test_maparr_set() {
+        maparr!(Map; ID1, ID2, ID3, ID4);
+
+        const INNER: MapSet = MapSet::from_keys([MapKey::ID1, MapKey::ID2]);
+        const ODD: MapSet = MapSet::from_keys([MapKey::ID1, MapKey::ID3]);
+
+        assert!(INNER.contains(MapKey::ID2));
+        assert!(!INNER.contains(MapKey::ID3));
+        assert_eq!(INNER.len(), 2);
+        assert_eq!(
+            (INNER | ODD).iter().collect::<Vec<_>>(),
+            vec![MapKey::ID1, MapKey::ID2, MapKey::ID3]
         );
-    };
-    (  $(#[$($derive_block:tt)*])* $publicity:vis $name:ident<$name_type:ty>; $($id:ident),* $(,)?) => {
-        $crate::__private_maparr!(
-            $(#[$($derive_block)*])*
-            $publicity
-            $name<$name_type>;
-            $($id),*
+        assert_eq!((INNER & ODD).iter().collect::<Vec<_>>(), vec![MapKey::ID1]);
+        assert_eq!((INNER - ODD).iter().collect::<Vec<_>>(), vec![MapKey::ID2]);
+        assert_eq!(
+            (!INNER).iter().collect::<Vec<_>>(),
+            vec![MapKey::ID3, MapKey::ID4]
         );
-    };
-    ( $name:ident; $($id:ident = $id_value:expr),* $(,)?) => {
-        $crate::__private_maparr!(
-            $name;
-            $($id = $id_value),*
-        )
-    };
-}
-
-#[cfg(test)]
-mod tests {
-    extern crate std;
-    use std::format;
-    use std::prelude::rust_2021::*;
-    use std::vec;
+        assert_eq!(MapSet::full().len(), 4);
+        assert!((INNER & ODD).is_subset(&INNER));
+        assert_eq!(format!("{INNER:?}"), "{ID1, ID2}");
+
+        let mut set = MapSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(MapKey::ID4));
+        assert!(!set.insert(MapKey::ID4));
+        assert!(set.remove(MapKey::ID4));
+        assert!(!set.remove(MapKey::ID4));
+
+        let set: MapSet = [MapKey::ID4, MapKey::ID2].into_iter().collect();
+        assert_eq!(
+            set.iter().rev().collect::<Vec<_>>(),
+            vec![MapKey::ID4, MapKey::ID2]
+        );
+        assert_eq!(set.iter().len(), 2);
 
-    use super::*;
+        let map: Map<bool> = set.into();
+        assert_eq!(map.as_ref(), &[false, true, false, true]);
+        assert_eq!(MapSet::from(map), set);
+    }
 
+    #[rustfmt::skip]
     #[test]
-    fn test_maparr_generic() {
-        maparr!(Map; ID1, ID2);
+    fn test_maparr_set_words() {
+        maparr!(Map; K0, K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14, K15, K16, K17, K18, K19, K20, K21, K22, K23, K24, K25, K26, K27, K28, K29, K30, K31, K32, K33, K34, K35, K36, K37, K38, K39, K40, K41, K42, K43, K44, K45, K46, K47, K48, K49, K50, K51, K52, K53, K54, K55, K56, K57, K58, K59, K60, K61, K62, K63, K64, K65, K66, K67, K68, K69);
 
-        let map = Map::new((Map::ID1, 1), (Map::ID2, 2));
-        assert_eq!(map[Map::ID1], 1);
-        assert_eq!(map[Map::ID2], 2);
+        assert_eq!(MapSet::full().len(), 70);
+        assert_eq!((!MapSet::from_keys([MapKey::K0, MapKey::K69])).len(), 68);
+        assert_eq!(MapSet::from_keys([MapKey::K69, MapKey::K64]).iter().collect::<Vec<_>>(), vec![MapKey::K64, MapKey::K69]);
     }
 
     #[test]
-    fn test_maparr() {
-        maparr!(Map<usize>; ID1, ID2);
+    fn test_maparr_subset() {
+        maparr!(Map; ID1, ID2, ID3, ID4; ODD = [ID1, ID3], EMPTY = [], LAST = [ID4,]);
+
+        let mut map = maparr!(Map; ID1 = 1, ID2 = 2, ID3 = 3, ID4 = 4);
+
+        let odd = map.subset(MapKey::ODD);
+        assert_eq!(odd.len(), 2);
+        assert_eq!(odd.sum::<i32>(), 4);
+        assert_eq!(odd.get(MapKey::ID3), Some(&3));
+        assert_eq!(odd.get(MapKey::ID2), None);
+        assert_eq!(
+            odd.iter_entries().rev().collect::<Vec<_>>(),
+            vec![(MapKey::ID3, &3), (MapKey::ID1, &1)]
+        );
+        assert_eq!(format!("{odd:?}"), "{ID1: 1, ID3: 3}");
+
+        assert!(map.subset(MapKey::EMPTY).is_empty());
+        assert_eq!(map.subset(MapKey::LAST).iter_entries().len(), 1);
+
+        let mut odd = map.subset_mut(MapKey::ODD);
+        *odd.get_mut(MapKey::ID1).unwrap() = 10;
+        assert!(odd.get_mut(MapKey::ID4).is_none());
+        for (_, value) in odd.iter_entries_mut() {
+            *value *= 2;
+        }
+        assert_eq!(map.as_ref(), &[20, 2, 6, 4]);
 
-        let map = Map::new((Map::ID1, 1), (Map::ID2, 2));
-        assert_eq!(map[Map::ID1], 1);
-        assert_eq!(map[Map::ID2], 2);
+        for (_, value) in map.subset_mut(!MapKey::ODD) {
+            *value = 0;
+        }
+        assert_eq!(map.as_ref(), &[20, 0, 6, 0]);
     }
 
     #[test]
-    fn test_maparr_value() {
-        maparr!(Map<usize>; ID1, ID2);
-        const MAP: Map = maparr!(Map; ID1 = 1, ID2 = 2);
+    fn test_maparr_range() {
+        use crate::Key;
+
+        maparr!(Planets; Mercury, Venus, Earth, Mars, Jupiter, Saturn);
+
+        let mut map = maparr!(Planets; for id => id.index());
+
+        assert_eq!(map.range(PlanetsKey::Mars..=PlanetsKey::Saturn), &[3, 4, 5]);
+        assert_eq!(map.range(..PlanetsKey::Earth), &[0, 1]);
+        assert_eq!(map.range(PlanetsKey::Jupiter..), &[4, 5]);
+        assert_eq!(
+            map.range(PlanetsKey::Mars..PlanetsKey::Venus),
+            &[] as &[usize]
+        );
 
-        assert_eq!(MAP[Map::ID1], 1);
-        assert_eq!(MAP[Map::ID2], 2);
+        map.range_mut(..=PlanetsKey::Venus).fill(0);
+        assert_eq!(map.as_ref(), &[0, 0, 2, 3, 4, 5]);
+
+        let entries = map
+            .range_entries(PlanetsKey::Earth..PlanetsKey::Jupiter)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![(PlanetsKey::Earth, &2), (PlanetsKey::Mars, &3)]
+        );
+
+        for (key, value) in map.range_entries_mut(PlanetsKey::Saturn..) {
+            *value = key.index() * 10;
+        }
+        assert_eq!(map[PlanetsKey::Saturn], 50);
+
+        assert_eq!(
+            PlanetsKey::range(PlanetsKey::Mercury..PlanetsKey::Jupiter),
+            &[
+                PlanetsKey::Mercury,
+                PlanetsKey::Venus,
+                PlanetsKey::Earth,
+                PlanetsKey::Mars
+            ]
+        );
+        assert_eq!(PlanetsKey::range(..).last(), Some(&PlanetsKey::Saturn));
+        assert_eq!(
+            <PlanetsKey as Key>::range(PlanetsKey::Saturn..PlanetsKey::Earth),
+            &[]
+        );
     }
 
     #[test]
-    fn test_maparr_generic_value() {
-        maparr!(Map; ID1, ID2);
-        const MAP: Map<bool> = maparr!(Map; ID1 = false, ID2 = true);
+    fn test_maparr_table() {
+        maparr!(State; Idle, Running, Done);
+        maparr!(Event; Start, Stop);
+        maparr!(Transitions<Option<StateKey>> = State * Event);
+
+        let mut table = Transitions::default();
+        table.set(StateKey::Idle, EventKey::Start, Some(StateKey::Running));
+        table[(StateKey::Running, EventKey::Stop)] = Some(StateKey::Done);
+
+        assert_eq!(
+            table[(StateKey::Idle, EventKey::Start)],
+            Some(StateKey::Running)
+        );
+        assert_eq!(*table.get(StateKey::Idle, EventKey::Stop), None);
+        assert_eq!(
+            table.row(StateKey::Running).as_ref(),
+            &[None, Some(StateKey::Done)]
+        );
+        assert_eq!(
+            table.column(EventKey::Start).as_ref(),
+            &[&Some(StateKey::Running), &None, &None]
+        );
+
+        for (_, value) in table.column_mut(EventKey::Stop).iter_entries_mut() {
+            **value = Some(StateKey::Idle);
+        }
+        *table.row_mut(StateKey::Done) = maparr!(Event; Start = None, Stop = None);
+        assert_eq!(
+            table
+                .rows()
+                .map(|row| row.iter().flatten().count())
+                .as_ref(),
+            &[2, 1, 0]
+        );
+
+        let transposed = table.transpose();
+        assert_eq!(
+            transposed[(EventKey::Stop, StateKey::Running)],
+            Some(StateKey::Idle)
+        );
+        assert_eq!(
+            transposed.row(EventKey::Start).as_ref(),
+            &[Some(StateKey::Running), None, None]
+        );
+        assert_eq!(transposed.transpose(), table);
 
-        assert!(!MAP[Map::ID1]);
-        assert!(MAP[Map::ID2]);
+        let named = table.map_with_key(|state, event, _| format!("{state}:{event}"));
+        assert_eq!(named[(StateKey::Done, EventKey::Stop)], "Done:Stop");
     }
 
     #[test]
-    #[should_panic]
-    fn test_maparr_generic_value_wrong_sorting() {
-        maparr!(Map; ID1, ID2);
-        let _map = maparr!(Map; ID2 = false, ID1 = false);
+    fn test_maparr_table_symmetric() {
+        maparr!(Planets; Mercury, Venus, Earth);
+        maparr!(Table = Planets * Planets);
+        maparr!(Distances = symmetric Planets);
+
+        let mut distances: Distances<i32> =
+            Distances::from_fn(|a, b| b.index() as i32 - a.index() as i32);
+        assert_eq!(distances[(PlanetsKey::Earth, PlanetsKey::Mercury)], 2);
+        assert_eq!(distances[(PlanetsKey::Mercury, PlanetsKey::Earth)], 2);
+        assert!(distances.as_table().is_symmetric());
+
+        distances.set(PlanetsKey::Venus, PlanetsKey::Earth, 10);
+        assert_eq!(distances[(PlanetsKey::Earth, PlanetsKey::Venus)], 10);
+        assert_eq!(*distances.column(PlanetsKey::Venus)[PlanetsKey::Earth], 10);
+        assert!(distances.as_table().is_symmetric());
+
+        let mut table: Table<i32> = distances.into_table();
+        table.set(PlanetsKey::Venus, PlanetsKey::Earth, 0);
+        assert!(!table.is_symmetric());
+        assert_eq!(Distances::from_table(table), None);
+
+        let transposed: Table<i32> = table.transpose();
+        assert_eq!(transposed[(PlanetsKey::Earth, PlanetsKey::Venus)], 0);
+        assert_eq!(
+            Distances::from_table(Table::splat(1)),
+            Some(Distances::splat(1))
+        );
+
+        let mut calls = 0;
+        let counted: Distances<i32> = Distances::from_fn(|_, _| {
+            calls += 1;
+            calls
+        });
+        assert_eq!(calls, 6);
+        assert_eq!(counted[(PlanetsKey::Mercury, PlanetsKey::Venus)], 2);
+        assert_eq!(counted[(PlanetsKey::Venus, PlanetsKey::Mercury)], 2);
+        assert!(counted.as_table().is_symmetric());
+
+        let mut calls = 0;
+        let mapped = counted.map_with_key(|_, _, value| {
+            calls += 1;
+            value * 10 + calls
+        });
+        assert_eq!(calls, 6);
+        assert!(mapped.as_table().is_symmetric());
+        assert_eq!(
+            format!("{:?}", Distances::splat(0).row(PlanetsKey::Earth)),
+            "{Mercury: 0, Venus: 0, Earth: 0}"
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn test_maparr_value_wrong_sorting() {
-        maparr!(Map<bool>; ID1, ID2);
-        let _map = maparr!(Map; ID2 = false, ID1 = false);
+    fn test_maparr_extend() {
+        use crate::MissingKeyError;
+
+        maparr!(Cars; Sedan, Coupe);
+        maparr!(Trucks<u32>; Pickup, Semi);
+        maparr!(Vehicles<u32> = Cars[Sedan, Coupe] + Trucks[Pickup, Semi]);
+        maparr!(Fleet = Vehicles[Sedan, Coupe, Pickup, Semi] + [Bus, Tram] + garage::Cars2[Roadster] + [Bike,]);
+
+        mod garage {
+            maparr!(pub Cars2; Roadster);
+        }
+
+        assert_eq!(
+            <VehiclesKey as crate::Key>::NAMES,
+            &["Sedan", "Coupe", "Pickup", "Semi"]
+        );
+        assert_eq!(<FleetKey as crate::Key>::COUNT, 8);
+        assert_eq!(
+            FleetKey::from(garage::Cars2Key::Roadster),
+            FleetKey::Roadster
+        );
+        assert_eq!(VehiclesKey::from(TrucksKey::Semi), VehiclesKey::Semi);
+        assert_eq!(CarsKey::try_from(VehiclesKey::Coupe), Ok(CarsKey::Coupe));
+
+        let err = CarsKey::try_from(VehiclesKey::Pickup).unwrap_err();
+        assert_eq!(err, MissingKeyError::new("Pickup"));
+        assert_eq!(err.to_string(), "key Pickup is missing in the key set");
+
+        let cars = maparr!(Cars; Sedan = 1, Coupe = 2);
+        let trucks = maparr!(Trucks; Pickup = 3, Semi = 4);
+
+        let vehicles: Vehicles = cars.embed(|_| 0);
+        assert_eq!(vehicles.as_ref(), &[1, 2, 0, 0]);
+
+        let vehicles = vehicles.merge_with(trucks.embed(|_| 0), |_, a, b| a + b);
+        assert_eq!(vehicles.as_ref(), &[1, 2, 3, 4]);
+
+        let trucks: Trucks = vehicles.project();
+        assert_eq!(trucks.as_ref(), &[3, 4]);
+
+        let fleet: Fleet<String> = vehicles
+            .map(|value| value.to_string())
+            .embed(|key: FleetKey| key.to_string());
+        assert_eq!(fleet[FleetKey::Bus], "Bus");
+        assert_eq!(fleet[FleetKey::Semi], "4");
+
+        let vehicles: Vehicles<String> = fleet.project();
+        let cars: Cars<String> = vehicles.project();
+        assert_eq!(cars.as_ref(), &["1", "2"]);
     }
 
-    #[allow(non_upper_case_globals)]
+    #[allow(non_upper_case_globals, dead_code)]
     #[rustfmt::skip]
     #[test]
     #[ignore]
@@ -687,40 +2011,40 @@ mod tests {
     fn check_interface() {
         maparr!(#[derive(Debug, Clone)] Map; ID_1, ID_2, ID_3, ID_4);
 
-        let mut value = Map::new(
-            (Map::ID_1, "Hello"),
-            (Map::ID_2, "World"),
-            (Map::ID_3, "Halo"),
-            (Map::ID_4, "Earth"),
-        );
+        let mut value = Map::new([
+            (MapKey::ID_1, "Hello"),
+            (MapKey::ID_2, "World"),
+            (MapKey::ID_3, "Halo"),
+            (MapKey::ID_4, "Earth"),
+        ]);
 
         assert_eq!(Map::len(), 4);
         assert!(!Map::is_empty());
 
         let dbg = format!("{value:?}");
-        assert_eq!(dbg, "Map { list: [\"Hello\", \"World\", \"Halo\", \"Earth\"] }");
+        assert_eq!(dbg, "{ID_1: \"Hello\", ID_2: \"World\", ID_3: \"Halo\", ID_4: \"Earth\"}");
 
-        let values = value.iter().into_iter().cloned().collect::<Vec<_>>();
+        let values = value.iter().cloned().collect::<Vec<_>>();
         assert_eq!(values, vec!["Hello", "World", "Halo", "Earth"]);
 
-        let values = value.clone().into_iter().collect::<Vec<_>>();
+        let values = value.into_iter().collect::<Vec<_>>();
         assert_eq!(values, vec!["Hello", "World", "Halo", "Earth"]);
 
         let values = value.as_ref();
         assert_eq!(values, &["Hello", "World", "Halo", "Earth"]);
 
-        let id_value = value.get(Map::ID_1);
+        let id_value = value.get(MapKey::ID_1);
         assert_eq!(*id_value, "Hello");
 
-        let id_value = value[Map::ID_1];
+        let id_value = value[MapKey::ID_1];
         assert_eq!(id_value, "Hello");
 
-        value.set(Map::ID_2, "123456789");
-        let id_value = value.get(Map::ID_2);
+        value.set(MapKey::ID_2, "123456789");
+        let id_value = value.get(MapKey::ID_2);
         assert_eq!(*id_value, "123456789");
 
-        value[Map::ID_2] = "1234567890";
-        let id_value = value.get(Map::ID_2);
+        value[MapKey::ID_2] = "1234567890";
+        let id_value = value.get(MapKey::ID_2);
         assert_eq!(*id_value, "1234567890");
     }
 
@@ -729,20 +2053,20 @@ mod tests {
     fn check_interface_generic() {
         maparr!(#[derive(Debug, Clone)] Map<String>; ID_1, ID_2, ID_3, ID_4);
 
-        let mut value = Map::new(
-            (Map::ID_1, String::from("Hello")),
-            (Map::ID_2, String::from("World")),
-            (Map::ID_3, String::from("Halo")),
-            (Map::ID_4, String::from("Earth")),
-        );
+        let mut value = Map::new([
+            (MapKey::ID_1, String::from("Hello")),
+            (MapKey::ID_2, String::from("World")),
+            (MapKey::ID_3, String::from("Halo")),
+            (MapKey::ID_4, String::from("Earth")),
+        ]);
 
         assert_eq!(Map::len(), 4);
         assert!(!Map::is_empty());
 
         let dbg = format!("{value:?}");
-        assert_eq!(dbg, "Map { list: [\"Hello\", \"World\", \"Halo\", \"Earth\"] }");
+        assert_eq!(dbg, "{ID_1: \"Hello\", ID_2: \"World\", ID_3: \"Halo\", ID_4: \"Earth\"}");
 
-        let values = value.iter().into_iter().cloned().collect::<Vec<_>>();
+        let values = value.iter().cloned().collect::<Vec<_>>();
         assert_eq!(values, vec!["Hello", "World", "Halo", "Earth"]);
 
         let values = value.clone().into_iter().collect::<Vec<_>>();
@@ -751,18 +2075,18 @@ mod tests {
         let values = value.as_ref();
         assert_eq!(values, &["Hello", "World", "Halo", "Earth"]);
 
-        let id_value = value.get(Map::ID_1);
+        let id_value = value.get(MapKey::ID_1);
         assert_eq!(*id_value, "Hello");
 
-        let id_value = &value[Map::ID_1];
+        let id_value = &value[MapKey::ID_1];
         assert_eq!(id_value, "Hello");
 
-        value.set(Map::ID_2, String::from("123456789"));
-        let id_value = value.get(Map::ID_2);
+        value.set(MapKey::ID_2, String::from("123456789"));
+        let id_value = value.get(MapKey::ID_2);
         assert_eq!(*id_value, "123456789");
 
-        value[Map::ID_2] = String::from("1234567890");
-        let id_value = value.get(Map::ID_2);
+        value[MapKey::ID_2] = String::from("1234567890");
+        let id_value = value.get(MapKey::ID_2);
         assert_eq!(*id_value, "1234567890");
     }
 }
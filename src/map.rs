@@ -0,0 +1,705 @@
+use core::{
+    marker::PhantomData,
+    mem,
+    mem::MaybeUninit,
+    ops::{Bound, Range, RangeBounds},
+    ptr,
+};
+
+use crate::{
+    iter::{Entries, EntriesMut, IntoEntries},
+    ArrSet, Embeds, Key, LengthError, MapArr, Subset, SubsetMut,
+};
+
+/// A small hashmap backed by an array.
+///
+/// It's a storage type of every map declared by [`maparr`](crate::maparr),
+/// which defines a key set `K` and an alias to `ArrMap<K, T, { K::COUNT }>`
+/// (as well as aliases to [`ArrSet`], [`ArrTable`](crate::ArrTable) and [`ArrSymTable`](crate::ArrSymTable)).
+///
+/// `N` is always equal to [`Key::COUNT`],
+/// it's a separate parameter only because `[T; K::COUNT]` can't be used on stable rust,
+/// sizes of the other storage types are parameters for the same reason.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrMap<K, T, const N: usize> {
+    list: [T; N],
+    key: PhantomData<K>,
+}
+
+/// Fails compilation if `N` does not correspond to the amount of keys.
+const fn check_size<K: Key, const N: usize>() {
+    const {
+        assert!(
+            N == K::COUNT,
+            "map size does not correspond to the amount of keys"
+        )
+    }
+}
+
+/// Check whether each key is listed once, so there are no missing keys.
+const fn each_key_once<K: Key, T, const N: usize>(entries: &[(K, T); N]) -> bool {
+    let mut seen = [false; N];
+    let mut i = 0;
+    while i < N {
+        let index = key_index(entries[i].0);
+        if seen[index] {
+            return false;
+        }
+
+        seen[index] = true;
+        i += 1;
+    }
+
+    true
+}
+
+/// Unwrap a value stored or yielded once per key.
+///
+/// Methods which move values between maps of the same keys keep them in `Option`s
+/// (or in iterators of exactly `N` values) and take each one exactly once,
+/// so a missing value would be a bug in this crate rather than a caller error.
+pub(crate) fn once_per_key<T>(value: Option<T>) -> T {
+    match value {
+        Some(value) => value,
+        None => unreachable!("each value is taken exactly once per key"),
+    }
+}
+
+/// Get an index of a key in `const` context.
+pub(crate) const fn key_index<K: Key>(key: K) -> usize {
+    // SAFETY: `Key` guarantees that `K` is a `#[repr(usize)]` fieldless enum,
+    // so its representation is its discriminant.
+    unsafe { *(&key as *const K).cast::<usize>() }
+}
+
+/// Get a range of indexes of keys, a reversed range is turned into an empty one.
+pub(crate) fn key_range<K: Key>(range: impl RangeBounds<K>) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(key) => key.index(),
+        Bound::Excluded(key) => key.index() + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(key) => key.index() + 1,
+        Bound::Excluded(key) => key.index(),
+        Bound::Unbounded => K::COUNT,
+    };
+
+    start..end.max(start)
+}
+
+impl<K: Key, T, const N: usize> ArrMap<K, T, N> {
+    const fn from_list(list: [T; N]) -> Self {
+        check_size::<K, N>();
+
+        Self {
+            list,
+            key: PhantomData,
+        }
+    }
+
+    /// Creates a map placing each value by its key.
+    ///
+    /// # Safety
+    ///
+    /// Each key must be listed exactly once.
+    #[doc(hidden)]
+    pub const unsafe fn __private_from_entries(entries: [(K, T); N]) -> Self {
+        let mut list = [const { MaybeUninit::<T>::uninit() }; N];
+
+        let ptr = entries.as_ptr();
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i` is in bounds and each value is read only once,
+            // `entries` are forgotten afterwards so values are not dropped twice.
+            let entry = unsafe { &*ptr.add(i) };
+            let value = unsafe { ptr::read(&entry.1) };
+            list[key_index(entry.0)] = MaybeUninit::new(value);
+            i += 1;
+        }
+
+        mem::forget(entries);
+
+        // SAFETY: the caller guarantees that each key is listed once,
+        // so each value is initialized.
+        Self::from_list(unsafe { ptr::read(list.as_ptr().cast::<[T; N]>()) })
+    }
+
+    #[doc(hidden)]
+    pub const fn __private_builder() -> Builder<K, T, N> {
+        Builder {
+            list: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+            key: PhantomData,
+        }
+    }
+
+    /// Replaces values of given keys, returns the map and the replaced values.
+    #[doc(hidden)]
+    pub const fn __private_replace_entries<const M: usize>(
+        mut self,
+        entries: [(K, T); M],
+    ) -> (Self, [T; M]) {
+        let mut replaced = [const { MaybeUninit::<T>::uninit() }; M];
+
+        let ptr = entries.as_ptr();
+        let mut i = 0;
+        while i < M {
+            // SAFETY: `i` is in bounds and each value is read only once,
+            // `entries` are forgotten afterwards so values are not dropped twice.
+            let entry = unsafe { &*ptr.add(i) };
+            let value = unsafe { ptr::read(&entry.1) };
+            replaced[i] = MaybeUninit::new(mem::replace(self.get_mut(entry.0), value));
+            i += 1;
+        }
+
+        mem::forget(entries);
+
+        // SAFETY: each value is initialized in the loop.
+        let replaced = unsafe { ptr::read(replaced.as_ptr().cast::<[T; M]>()) };
+
+        (self, replaced)
+    }
+
+    /// Creates a new structure, entries can be listed in any order.
+    ///
+    /// Only the [`maparr`](crate::maparr) value syntax is checked at compile time,
+    /// `new` checks keys when it's called, so a missing key is a compilation error
+    /// only in `const` context. Use [`ArrMap::try_new`] to handle it at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is listed more than once (so another key is missing).
+    pub const fn new(entries: [(K, T); N]) -> Self {
+        if !each_key_once(&entries) {
+            panic!("a key is listed more than once");
+        }
+
+        // SAFETY: each key is listed once as checked above.
+        unsafe { Self::__private_from_entries(entries) }
+    }
+
+    /// Creates a new structure, entries can be listed in any order,
+    /// returns `None` if a key is listed more than once (so another key is missing).
+    pub fn try_new(entries: [(K, T); N]) -> Option<Self> {
+        if each_key_once(&entries) {
+            // SAFETY: each key is listed once as checked above.
+            Some(unsafe { Self::__private_from_entries(entries) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a map from an array of values listed in keys declaration order.
+    pub const fn from_array(list: [T; N]) -> Self {
+        Self::from_list(list)
+    }
+
+    /// Converts the map into an array of values listed in keys declaration order.
+    pub const fn into_array(self) -> [T; N] {
+        // SAFETY: the map is forgotten right after, so values are not dropped twice.
+        let list = unsafe { ptr::read(&self.list) };
+        mem::forget(self);
+
+        list
+    }
+
+    /// Creates a map evaluating a value for each key.
+    pub fn from_fn<F>(mut func: F) -> Self
+    where
+        F: FnMut(K) -> T,
+    {
+        Self::from_list(core::array::from_fn(|i| func(K::ALL[i])))
+    }
+
+    /// Creates a map evaluating a value for each key,
+    /// returns the first error if any.
+    pub fn try_from_fn<E, F>(mut func: F) -> Result<Self, E>
+    where
+        F: FnMut(K) -> Result<T, E>,
+    {
+        let mut error = None;
+        let list: [Option<T>; N] = core::array::from_fn(|i| {
+            if error.is_some() {
+                return None;
+            }
+
+            match func(K::ALL[i]) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            }
+        });
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(Self::from_list(list.map(once_per_key))),
+        }
+    }
+
+    /// Creates a map with the same value for each key.
+    pub fn splat(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_list(core::array::from_fn(|_| value.clone()))
+    }
+
+    /// Get an object by an id.
+    pub const fn get(&self, id: K) -> &T {
+        // SAFETY: a key index is always in `0..N` range.
+        unsafe { &*self.list.as_ptr().add(key_index(id)) }
+    }
+
+    /// Get a mutable object by an id.
+    pub const fn get_mut(&mut self, id: K) -> &mut T {
+        // SAFETY: a key index is always in `0..N` range.
+        unsafe { &mut *self.list.as_mut_ptr().add(key_index(id)) }
+    }
+
+    /// Set an object by an id.
+    pub fn set(&mut self, id: K, value: T) {
+        *self.get_mut(id) = value
+    }
+
+    /// Get mutable objects by a list of ids,
+    /// returns `None` if an id is listed more than once.
+    pub fn get_many_mut<const M: usize>(&mut self, ids: [K; M]) -> Option<[&mut T; M]> {
+        self.list.get_disjoint_mut(ids.map(key_index)).ok()
+    }
+
+    /// Swap objects of 2 ids.
+    pub const fn swap(&mut self, a: K, b: K) {
+        self.list.swap(key_index(a), key_index(b))
+    }
+
+    /// Replace an object by an id, returning the old one.
+    pub const fn replace(&mut self, id: K, value: T) -> T {
+        mem::replace(self.get_mut(id), value)
+    }
+
+    /// Returns the map with a value of a given key replaced together with the old value.
+    ///
+    /// It's a `const` alternative of [`ArrMap::with`] for values which are not `Copy`.
+    pub const fn replace_with(mut self, id: K, value: T) -> (Self, T) {
+        let old = self.replace(id, value);
+        (self, old)
+    }
+
+    /// Take an object by an id, leaving a default value in its place.
+    pub fn take(&mut self, id: K) -> T
+    where
+        T: Default,
+    {
+        mem::take(self.get_mut(id))
+    }
+
+    /// Update an object by an id in place, returning a result of a given function.
+    pub fn update<R, F>(&mut self, id: K, func: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        func(self.get_mut(id))
+    }
+
+    /// Iterate objects.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Iterate objects mutable.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    /// Iterate objects together with their keys.
+    pub fn iter_entries(&self) -> Entries<'_, K, T> {
+        Entries::new(K::ALL, &self.list)
+    }
+
+    /// Iterate objects mutable together with their keys.
+    pub fn iter_entries_mut(&mut self) -> EntriesMut<'_, K, T> {
+        EntriesMut::new(K::ALL, &mut self.list)
+    }
+
+    /// Converts the map into an iterator over objects together with their keys.
+    pub fn into_entries(self) -> IntoEntries<K, T, N> {
+        IntoEntries::new(K::ALL, self.into_array())
+    }
+
+    /// Get values of a given range of keys.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> &[T] {
+        &self.list[key_range(range)]
+    }
+
+    /// Get mutable values of a given range of keys.
+    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> &mut [T] {
+        &mut self.list[key_range(range)]
+    }
+
+    /// Iterate objects of a given range of keys together with their keys.
+    pub fn range_entries<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, T> {
+        let range = key_range(range);
+        Entries::new(&K::ALL[range.clone()], &self.list[range])
+    }
+
+    /// Iterate objects of a given range of keys mutable together with their keys.
+    pub fn range_entries_mut<R: RangeBounds<K>>(&mut self, range: R) -> EntriesMut<'_, K, T> {
+        let range = key_range(range);
+        EntriesMut::new(&K::ALL[range.clone()], &mut self.list[range])
+    }
+
+    /// Converts the map into a map of an extended key set,
+    /// evaluating values of keys which are not in this key set by `fill`.
+    pub fn embed<E, F, const M: usize>(self, mut fill: F) -> ArrMap<E, T, M>
+    where
+        E: Embeds<K>,
+        K: TryFrom<E>,
+        F: FnMut(E) -> T,
+    {
+        let mut values = self.into_map(Some);
+        ArrMap::from_fn(|key| match K::try_from(key) {
+            Ok(part_key) => once_per_key(values.get_mut(part_key).take()),
+            Err(_) => fill(key),
+        })
+    }
+
+    /// Converts the map into a map of a part of its key set,
+    /// dropping values of keys which are not in the part.
+    pub fn project<P, const M: usize>(self) -> ArrMap<P, T, M>
+    where
+        P: Key,
+        K: Embeds<P>,
+    {
+        let mut values = self.into_map(Some);
+        ArrMap::from_fn(|key| once_per_key(values.get_mut(K::from(key)).take()))
+    }
+
+    /// Get a view of values of a given subset of keys.
+    pub fn subset<const W: usize>(&self, keys: ArrSet<K, W>) -> Subset<'_, K, T, N, W> {
+        Subset::new(self, keys)
+    }
+
+    /// Get a mutable view of values of a given subset of keys.
+    pub fn subset_mut<const W: usize>(&mut self, keys: ArrSet<K, W>) -> SubsetMut<'_, K, T, N, W> {
+        SubsetMut::new(self, keys)
+    }
+
+    /// Map values to new ones.
+    pub fn map<R, F>(&self, func: F) -> ArrMap<K, R, N>
+    where
+        F: FnMut(&T) -> R,
+    {
+        ArrMap::from_list(self.list.each_ref().map(func))
+    }
+
+    /// Map values to new ones providing their keys.
+    pub fn map_with_key<R, F>(&self, mut func: F) -> ArrMap<K, R, N>
+    where
+        F: FnMut(K, &T) -> R,
+    {
+        ArrMap::from_fn(|key| func(key, self.get(key)))
+    }
+
+    /// Map values to new ones consuming the map.
+    pub fn into_map<R, F>(self, func: F) -> ArrMap<K, R, N>
+    where
+        F: FnMut(T) -> R,
+    {
+        ArrMap::from_list(self.into_array().map(func))
+    }
+
+    /// Map values to new ones by a fallible function providing their keys,
+    /// returns the first error together with its key if any.
+    pub fn try_map<R, E, F>(&self, mut func: F) -> Result<ArrMap<K, R, N>, (K, E)>
+    where
+        F: FnMut(K, &T) -> Result<R, E>,
+    {
+        ArrMap::try_from_fn(|key| func(key, self.get(key)).map_err(|err| (key, err)))
+    }
+
+    /// Convert values into a different type.
+    pub fn map_into<U>(self) -> ArrMap<K, U, N>
+    where
+        T: Into<U>,
+    {
+        self.into_map(Into::into)
+    }
+
+    /// Borrow each value, analog of [`array::each_ref`](https://doc.rust-lang.org/std/primitive.array.html#method.each_ref).
+    pub fn each_ref(&self) -> ArrMap<K, &T, N> {
+        ArrMap::from_list(self.list.each_ref())
+    }
+
+    /// Borrow each value mutably, analog of [`array::each_mut`](https://doc.rust-lang.org/std/primitive.array.html#method.each_mut).
+    pub fn each_mut(&mut self) -> ArrMap<K, &mut T, N> {
+        ArrMap::from_list(self.list.each_mut())
+    }
+
+    /// Combine values of two maps into pairs.
+    pub fn zip<U>(self, other: ArrMap<K, U, N>) -> ArrMap<K, (T, U), N> {
+        let mut other = other.into_iter();
+        self.into_map(|value| (value, once_per_key(other.next())))
+    }
+
+    /// Combine values of two maps by a given function providing their keys.
+    pub fn zip_with<U, R, F>(&self, other: &ArrMap<K, U, N>, mut func: F) -> ArrMap<K, R, N>
+    where
+        F: FnMut(K, &T, &U) -> R,
+    {
+        ArrMap::from_fn(|key| func(key, self.get(key), other.get(key)))
+    }
+
+    /// Merge values of two maps by a given function providing their keys.
+    pub fn merge_with<F>(self, other: Self, mut func: F) -> Self
+    where
+        F: FnMut(K, T, T) -> T,
+    {
+        let mut other = other.into_entries();
+        self.into_map(|value| {
+            let (key, other) = once_per_key(other.next());
+            func(key, value, other)
+        })
+    }
+
+    /// Sums values together.
+    pub fn sum<'a, R>(&'a self) -> R
+    where
+        R: core::iter::Sum<&'a T>,
+    {
+        self.list.iter().sum()
+    }
+}
+
+impl<K: Key, A, B, const N: usize> ArrMap<K, (A, B), N> {
+    /// Split a map of pairs into 2 maps, an opposite of [`ArrMap::zip`].
+    pub fn unzip(self) -> (ArrMap<K, A, N>, ArrMap<K, B, N>) {
+        let mut seconds: [Option<B>; N] = [const { None }; N];
+        let mut i = 0;
+        let firsts = self.into_map(|(first, second)| {
+            seconds[i] = Some(second);
+            i += 1;
+            first
+        });
+
+        (firsts, ArrMap::from_list(seconds.map(once_per_key)))
+    }
+}
+
+impl<K: Key, T, const N: usize> ArrMap<K, Option<T>, N> {
+    /// Converts a map of options into a map of values if all of them are `Some`.
+    pub fn transpose(self) -> Option<ArrMap<K, T, N>> {
+        let mut values = self.into_iter();
+        ArrMap::try_from_fn(|_| once_per_key(values.next()).ok_or(())).ok()
+    }
+}
+
+impl<K: Key, T, E, const N: usize> ArrMap<K, Result<T, E>, N> {
+    /// Converts a map of results into a map of values if all of them are `Ok`,
+    /// returns the first error together with its key otherwise.
+    pub fn transpose(self) -> Result<ArrMap<K, T, N>, (K, E)> {
+        let mut entries = self.into_entries();
+        ArrMap::try_from_fn(|_| {
+            let (key, value) = once_per_key(entries.next());
+            value.map_err(|err| (key, err))
+        })
+    }
+}
+
+impl<K: Key, T: Copy, const N: usize> ArrMap<K, T, N> {
+    /// Returns the map with a value of a given key replaced.
+    ///
+    /// It's a `const` alternative of [`ArrMap::set`].
+    /// It requires `T: Copy` because the old value can't be dropped in a `const fn`,
+    /// use [`ArrMap::replace_with`] to get the old value back instead.
+    pub const fn with(mut self, id: K, value: T) -> Self {
+        *self.get_mut(id) = value;
+        self
+    }
+}
+
+/// A helper which builds a map key by key in `const` context.
+#[doc(hidden)]
+pub struct Builder<K, T, const N: usize> {
+    list: [MaybeUninit<T>; N],
+    len: usize,
+    key: PhantomData<K>,
+}
+
+impl<K: Key, T, const N: usize> Builder<K, T, N> {
+    /// Get a key which value is expected next.
+    pub const fn next_key(&self) -> Option<K> {
+        if self.len < N {
+            Some(K::ALL[self.len])
+        } else {
+            None
+        }
+    }
+
+    /// Set a value of the next key.
+    pub const fn push(&mut self, value: T) {
+        self.list[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+    }
+
+    /// Get a built map.
+    pub const fn finish(self) -> ArrMap<K, T, N> {
+        assert!(self.len == N, "not every key has a value");
+
+        // SAFETY: each value is initialized as it's checked above.
+        ArrMap::from_list(unsafe { ptr::read(self.list.as_ptr().cast::<[T; N]>()) })
+    }
+}
+
+impl<K: Key, const N: usize> ArrMap<K, (), N> {
+    /// Get a map size.
+    pub const fn len() -> usize {
+        N
+    }
+
+    /// Check whether the map is empty.
+    pub const fn is_empty() -> bool {
+        N == 0
+    }
+
+    /// Get an list of keys supported.
+    pub const fn keys() -> [K; N] {
+        check_size::<K, N>();
+
+        // SAFETY: `K::ALL` has exactly `K::COUNT` keys.
+        unsafe { *K::ALL.as_ptr().cast::<[K; N]>() }
+    }
+
+    /// Get an list of keys names supported.
+    pub const fn names() -> [&'static str; N] {
+        check_size::<K, N>();
+
+        // SAFETY: `K::NAMES` has exactly `K::COUNT` names.
+        unsafe { *K::NAMES.as_ptr().cast::<[&'static str; N]>() }
+    }
+}
+
+impl<K: Key, T, const N: usize> MapArr for ArrMap<K, T, N> {
+    type Key = K;
+    type Value = T;
+
+    const LEN: usize = N;
+
+    fn get(&self, key: Self::Key) -> &Self::Value {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: Self::Key) -> &mut Self::Value {
+        self.get_mut(key)
+    }
+
+    fn keys() -> &'static [Self::Key] {
+        K::ALL
+    }
+
+    fn names() -> &'static [&'static str] {
+        K::NAMES
+    }
+
+    fn iter(&self) -> core::slice::Iter<'_, Self::Value> {
+        self.list.iter()
+    }
+}
+
+impl<K: Key, T, const N: usize> core::ops::Index<K> for ArrMap<K, T, N> {
+    type Output = T;
+
+    fn index(&self, index: K) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<K: Key, T, const N: usize> core::ops::IndexMut<K> for ArrMap<K, T, N> {
+    fn index_mut(&mut self, index: K) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<K: Key, T, const N: usize> From<ArrMap<K, T, N>> for [T; N] {
+    fn from(map: ArrMap<K, T, N>) -> [T; N] {
+        map.list
+    }
+}
+
+impl<K: Key, T, const N: usize> From<[T; N]> for ArrMap<K, T, N> {
+    fn from(list: [T; N]) -> Self {
+        Self::from_list(list)
+    }
+}
+
+impl<K: Key, T: Clone, const N: usize> TryFrom<&[T]> for ArrMap<K, T, N> {
+    type Error = LengthError;
+
+    fn try_from(list: &[T]) -> Result<Self, Self::Error> {
+        match <&[T; N]>::try_from(list) {
+            Ok(list) => Ok(Self::from_list(list.clone())),
+            Err(_) => Err(LengthError::new(N, list.len())),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Key, T, const N: usize> TryFrom<alloc::vec::Vec<T>> for ArrMap<K, T, N> {
+    type Error = LengthError;
+
+    fn try_from(list: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+        let len = list.len();
+        match <[T; N]>::try_from(list) {
+            Ok(list) => Ok(Self::from_list(list)),
+            Err(_) => Err(LengthError::new(N, len)),
+        }
+    }
+}
+
+impl<K: Key, T, const N: usize> core::convert::AsRef<[T]> for ArrMap<K, T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.list
+    }
+}
+
+impl<K: Key, T, const N: usize> core::iter::IntoIterator for ArrMap<K, T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<Self::Item, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<K: Key, T: Default, const N: usize> Default for ArrMap<K, T, N> {
+    fn default() -> Self {
+        Self::from_list(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<'a, K: Key, T, const N: usize> core::iter::IntoIterator for &'a ArrMap<K, T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+impl<'a, K: Key, T, const N: usize> core::iter::IntoIterator for &'a mut ArrMap<K, T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter_mut()
+    }
+}
+
+impl<K: Key, T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrMap<K, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_entries()).finish()
+    }
+}
@@ -0,0 +1,263 @@
+use core::marker::PhantomData;
+
+use crate::{iter::SetIter, map::key_index, ArrMap, Key};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of keys backed by a bit array, see [`ArrMap`] on how [`maparr`](crate::maparr) uses such types.
+///
+/// `W` is an amount of `u64` words required to store [`Key::COUNT`] bits.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrSet<K, const W: usize> {
+    words: [u64; W],
+    key: PhantomData<K>,
+}
+
+/// Fails compilation if `W` does not correspond to the amount of keys.
+const fn check_words<K: Key, const W: usize>() {
+    const {
+        assert!(
+            W == K::COUNT.div_ceil(WORD_BITS),
+            "set size does not correspond to the amount of keys"
+        )
+    }
+}
+
+impl<K: Key, const W: usize> ArrSet<K, W> {
+    const fn from_words(words: [u64; W]) -> Self {
+        check_words::<K, W>();
+
+        Self {
+            words,
+            key: PhantomData,
+        }
+    }
+
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self::from_words([0; W])
+    }
+
+    /// Creates a set of all keys.
+    pub const fn full() -> Self {
+        Self::new().complement()
+    }
+
+    /// Creates a set of given keys.
+    pub const fn from_keys<const M: usize>(keys: [K; M]) -> Self {
+        let mut set = Self::new();
+        let mut i = 0;
+        while i < M {
+            set.insert(keys[i]);
+            i += 1;
+        }
+
+        set
+    }
+
+    /// Check whether a key is in the set.
+    pub const fn contains(&self, key: K) -> bool {
+        let (word, bit) = position(key);
+        self.words[word] & bit != 0
+    }
+
+    /// Add a key to the set, returns whether the key was not in the set.
+    pub const fn insert(&mut self, key: K) -> bool {
+        let (word, bit) = position(key);
+        let inserted = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        inserted
+    }
+
+    /// Remove a key from the set, returns whether the key was in the set.
+    pub const fn remove(&mut self, key: K) -> bool {
+        let (word, bit) = position(key);
+        let removed = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        removed
+    }
+
+    /// Get an amount of keys in the set.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < W {
+            len += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+
+        len
+    }
+
+    /// Check whether the set is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns keys which are in either of sets.
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < W {
+            self.words[i] |= other.words[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Returns keys which are in both sets.
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < W {
+            self.words[i] &= other.words[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Returns keys which are in the set but not in the other one.
+    pub const fn difference(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < W {
+            self.words[i] &= !other.words[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Returns keys which are not in the set.
+    pub const fn complement(mut self) -> Self {
+        let mut i = 0;
+        while i < W {
+            self.words[i] = !self.words[i];
+            i += 1;
+        }
+
+        let rest = K::COUNT % WORD_BITS;
+        if rest != 0 {
+            self.words[W - 1] &= (1 << rest) - 1;
+        }
+
+        self
+    }
+
+    /// Check whether every key of the set is in the other one.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < W {
+            if self.words[i] & !other.words[i] != 0 {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Iterate keys of the set in declaration order.
+    pub fn iter(&self) -> SetIter<K, W> {
+        SetIter::new(K::ALL, *self)
+    }
+}
+
+/// Get a word index and a bit mask of a key.
+const fn position<K: Key>(key: K) -> (usize, u64) {
+    let index = key_index(key);
+    (index / WORD_BITS, 1 << (index % WORD_BITS))
+}
+
+impl<K: Key, const W: usize> Default for ArrSet<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Key, const W: usize> core::ops::BitOr for ArrSet<K, W> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        self.union(other)
+    }
+}
+
+impl<K: Key, const W: usize> core::ops::BitAnd for ArrSet<K, W> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        self.intersection(other)
+    }
+}
+
+impl<K: Key, const W: usize> core::ops::Sub for ArrSet<K, W> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.difference(other)
+    }
+}
+
+impl<K: Key, const W: usize> core::ops::Not for ArrSet<K, W> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<K: Key, const W: usize> FromIterator<K> for ArrSet<K, W> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: Key, const W: usize> Extend<K> for ArrSet<K, W> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<K: Key, const W: usize> core::iter::IntoIterator for ArrSet<K, W> {
+    type Item = K;
+    type IntoIter = SetIter<K, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Key, const W: usize> core::iter::IntoIterator for &ArrSet<K, W> {
+    type Item = K;
+    type IntoIter = SetIter<K, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Key, const W: usize, const N: usize> From<ArrMap<K, bool, N>> for ArrSet<K, W> {
+    fn from(map: ArrMap<K, bool, N>) -> Self {
+        map.iter_entries()
+            .filter(|(_, &value)| value)
+            .map(|(key, _)| key)
+            .collect()
+    }
+}
+
+impl<K: Key, const W: usize, const N: usize> From<ArrSet<K, W>> for ArrMap<K, bool, N> {
+    fn from(set: ArrSet<K, W>) -> Self {
+        ArrMap::from_fn(|key| set.contains(key))
+    }
+}
+
+impl<K: Key, const W: usize> core::fmt::Debug for ArrSet<K, W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    iter::{SubsetEntries, SubsetEntriesMut},
+    ArrMap, ArrSet, Key,
+};
+
+/// A view of map values of a subset of keys, see [`ArrMap::subset`].
+pub struct Subset<'a, K: Key, T, const N: usize, const W: usize> {
+    map: &'a ArrMap<K, T, N>,
+    keys: ArrSet<K, W>,
+}
+
+impl<'a, K: Key, T, const N: usize, const W: usize> Subset<'a, K, T, N, W> {
+    pub(crate) fn new(map: &'a ArrMap<K, T, N>, keys: ArrSet<K, W>) -> Self {
+        Self { map, keys }
+    }
+
+    /// Get keys of the subset.
+    pub const fn keys(&self) -> ArrSet<K, W> {
+        self.keys
+    }
+
+    /// Get an amount of keys in the subset.
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check whether the subset is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Check whether a key is in the subset.
+    pub const fn contains(&self, key: K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Get an object by an id if it's in the subset.
+    pub const fn get(&self, id: K) -> Option<&'a T> {
+        if self.keys.contains(id) {
+            Some(self.map.get(id))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate objects of the subset together with their keys.
+    pub fn iter_entries(&self) -> SubsetEntries<'a, K, T, W> {
+        SubsetEntries::new(self.map.iter_entries(), self.keys)
+    }
+
+    /// Sums values of the subset together.
+    pub fn sum<R>(&self) -> R
+    where
+        R: core::iter::Sum<&'a T>,
+    {
+        self.iter_entries().map(|(_, value)| value).sum()
+    }
+}
+
+impl<K: Key, T, const N: usize, const W: usize> Clone for Subset<'_, K, T, N, W> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Key, T, const N: usize, const W: usize> Copy for Subset<'_, K, T, N, W> {}
+
+impl<'a, K: Key, T, const N: usize, const W: usize> core::iter::IntoIterator
+    for Subset<'a, K, T, N, W>
+{
+    type Item = (K, &'a T);
+    type IntoIter = SubsetEntries<'a, K, T, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_entries()
+    }
+}
+
+impl<K: Key, T: core::fmt::Debug, const N: usize, const W: usize> core::fmt::Debug
+    for Subset<'_, K, T, N, W>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_entries()).finish()
+    }
+}
+
+/// A mutable view of map values of a subset of keys, see [`ArrMap::subset_mut`].
+pub struct SubsetMut<'a, K: Key, T, const N: usize, const W: usize> {
+    map: &'a mut ArrMap<K, T, N>,
+    keys: ArrSet<K, W>,
+}
+
+impl<'a, K: Key, T, const N: usize, const W: usize> SubsetMut<'a, K, T, N, W> {
+    pub(crate) fn new(map: &'a mut ArrMap<K, T, N>, keys: ArrSet<K, W>) -> Self {
+        Self { map, keys }
+    }
+
+    /// Get keys of the subset.
+    pub const fn keys(&self) -> ArrSet<K, W> {
+        self.keys
+    }
+
+    /// Get an amount of keys in the subset.
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check whether the subset is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Check whether a key is in the subset.
+    pub const fn contains(&self, key: K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Get an object by an id if it's in the subset.
+    pub const fn get(&self, id: K) -> Option<&T> {
+        if self.keys.contains(id) {
+            Some(self.map.get(id))
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable object by an id if it's in the subset.
+    pub const fn get_mut(&mut self, id: K) -> Option<&mut T> {
+        if self.keys.contains(id) {
+            Some(self.map.get_mut(id))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate objects of the subset together with their keys.
+    pub fn iter_entries(&self) -> SubsetEntries<'_, K, T, W> {
+        SubsetEntries::new(self.map.iter_entries(), self.keys)
+    }
+
+    /// Iterate objects of the subset mutable together with their keys.
+    pub fn iter_entries_mut(&mut self) -> SubsetEntriesMut<'_, K, T, W> {
+        SubsetEntriesMut::new(self.map.iter_entries_mut(), self.keys)
+    }
+
+    /// Sums values of the subset together.
+    pub fn sum<'b, R>(&'b self) -> R
+    where
+        R: core::iter::Sum<&'b T>,
+    {
+        self.iter_entries().map(|(_, value)| value).sum()
+    }
+}
+
+impl<'a, K: Key, T, const N: usize, const W: usize> core::iter::IntoIterator
+    for SubsetMut<'a, K, T, N, W>
+{
+    type Item = (K, &'a mut T);
+    type IntoIter = SubsetEntriesMut<'a, K, T, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SubsetEntriesMut::new(self.map.iter_entries_mut(), self.keys)
+    }
+}
+
+impl<K: Key, T: core::fmt::Debug, const N: usize, const W: usize> core::fmt::Debug
+    for SubsetMut<'_, K, T, N, W>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_entries()).finish()
+    }
+}
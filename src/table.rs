@@ -0,0 +1,270 @@
+use crate::{map::once_per_key, ArrMap, Key};
+
+/// A small 2-D hashmap backed by an array of arrays, indexed by pairs of keys,
+/// see [`ArrMap`] on how [`maparr`](crate::maparr) uses such types.
+///
+/// Each row is an [`ArrMap`] over column keys `C`,
+/// so a row view is a map itself.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrTable<R, C, T, const ROWS: usize, const COLS: usize> {
+    rows: ArrMap<R, ArrMap<C, T, COLS>, ROWS>,
+}
+
+impl<R: Key, C: Key, T, const ROWS: usize, const COLS: usize> ArrTable<R, C, T, ROWS, COLS> {
+    /// Creates a table from its rows.
+    pub const fn from_rows(rows: ArrMap<R, ArrMap<C, T, COLS>, ROWS>) -> Self {
+        Self { rows }
+    }
+
+    /// Converts the table into its rows.
+    pub fn into_rows(self) -> ArrMap<R, ArrMap<C, T, COLS>, ROWS> {
+        self.rows
+    }
+
+    /// Creates a table evaluating a value for each pair of keys.
+    pub fn from_fn<F>(mut func: F) -> Self
+    where
+        F: FnMut(R, C) -> T,
+    {
+        Self::from_rows(ArrMap::from_fn(|row| ArrMap::from_fn(|col| func(row, col))))
+    }
+
+    /// Creates a table with the same value for each pair of keys.
+    pub fn splat(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(|_, _| value.clone())
+    }
+
+    /// Get an object by a pair of ids.
+    pub const fn get(&self, row: R, col: C) -> &T {
+        self.rows.get(row).get(col)
+    }
+
+    /// Get a mutable object by a pair of ids.
+    pub const fn get_mut(&mut self, row: R, col: C) -> &mut T {
+        self.rows.get_mut(row).get_mut(col)
+    }
+
+    /// Set an object by a pair of ids.
+    pub fn set(&mut self, row: R, col: C, value: T) {
+        *self.get_mut(row, col) = value
+    }
+
+    /// Get rows of the table.
+    pub const fn rows(&self) -> &ArrMap<R, ArrMap<C, T, COLS>, ROWS> {
+        &self.rows
+    }
+
+    /// Get mutable rows of the table.
+    pub const fn rows_mut(&mut self) -> &mut ArrMap<R, ArrMap<C, T, COLS>, ROWS> {
+        &mut self.rows
+    }
+
+    /// Get a row by an id.
+    pub const fn row(&self, row: R) -> &ArrMap<C, T, COLS> {
+        self.rows.get(row)
+    }
+
+    /// Get a mutable row by an id.
+    pub const fn row_mut(&mut self, row: R) -> &mut ArrMap<C, T, COLS> {
+        self.rows.get_mut(row)
+    }
+
+    /// Get a column by an id as a map of references.
+    pub fn column(&self, col: C) -> ArrMap<R, &T, ROWS> {
+        self.rows.each_ref().into_map(|row| row.get(col))
+    }
+
+    /// Get a mutable column by an id as a map of references.
+    pub fn column_mut(&mut self, col: C) -> ArrMap<R, &mut T, ROWS> {
+        self.rows.each_mut().into_map(|row| row.get_mut(col))
+    }
+
+    /// Map values to new ones providing their keys.
+    pub fn map_with_key<U, F>(&self, mut func: F) -> ArrTable<R, C, U, ROWS, COLS>
+    where
+        F: FnMut(R, C, &T) -> U,
+    {
+        ArrTable::from_fn(|row, col| func(row, col, self.get(row, col)))
+    }
+
+    /// Swap rows and columns of the table.
+    pub fn transpose(self) -> ArrTable<C, R, T, COLS, ROWS> {
+        let mut rows = self.rows.into_map(|cols| cols.into_iter());
+        ArrTable::from_fn(|_, row| once_per_key(rows[row].next()))
+    }
+}
+
+impl<K: Key, T, const N: usize> ArrTable<K, K, T, N, N> {
+    /// Check whether the table is equal to its transposition.
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        K::ALL.iter().all(|&row| {
+            K::ALL
+                .iter()
+                .all(|&col| self.get(row, col) == self.get(col, row))
+        })
+    }
+}
+
+impl<R: Key, C: Key, T, const ROWS: usize, const COLS: usize> core::ops::Index<(R, C)>
+    for ArrTable<R, C, T, ROWS, COLS>
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (R, C)) -> &Self::Output {
+        self.get(row, col)
+    }
+}
+
+impl<R: Key, C: Key, T, const ROWS: usize, const COLS: usize> core::ops::IndexMut<(R, C)>
+    for ArrTable<R, C, T, ROWS, COLS>
+{
+    fn index_mut(&mut self, (row, col): (R, C)) -> &mut Self::Output {
+        self.get_mut(row, col)
+    }
+}
+
+impl<R: Key, C: Key, T: Default, const ROWS: usize, const COLS: usize> Default
+    for ArrTable<R, C, T, ROWS, COLS>
+{
+    fn default() -> Self {
+        Self::from_rows(ArrMap::default())
+    }
+}
+
+impl<R: Key, C: Key, T: core::fmt::Debug, const ROWS: usize, const COLS: usize> core::fmt::Debug
+    for ArrTable<R, C, T, ROWS, COLS>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.rows.fmt(f)
+    }
+}
+
+/// A square 2-D hashmap which is always symmetric, so `(a, b)` and `(b, a)` have the same value,
+/// see [`ArrMap`] on how [`maparr`](crate::maparr) uses such types.
+///
+/// Unlike [`ArrTable`] it doesn't give a mutable access to a single value,
+/// the only way to change it is [`ArrSymTable::set`] which writes both `(a, b)` and `(b, a)`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrSymTable<K, T, const N: usize> {
+    table: ArrTable<K, K, T, N, N>,
+}
+
+impl<K: Key, T, const N: usize> ArrSymTable<K, T, N> {
+    /// Creates a table evaluating a value for each pair of keys,
+    /// `func(a, b)` is called once for `a <= b` and its result is cloned into `(b, a)`.
+    pub fn from_fn<F>(mut func: F) -> Self
+    where
+        F: FnMut(K, K) -> T,
+        T: Clone,
+    {
+        let upper: ArrTable<K, K, Option<T>, N, N> =
+            ArrTable::from_fn(|row, col| (row <= col).then(|| func(row, col)));
+        Self {
+            table: ArrTable::from_fn(|row: K, col: K| {
+                match upper.get(row.min(col), row.max(col)) {
+                    Some(value) => value.clone(),
+                    None => unreachable!("each ordered pair is evaluated"),
+                }
+            }),
+        }
+    }
+
+    /// Creates a table with the same value for each pair of keys.
+    pub fn splat(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            table: ArrTable::splat(value),
+        }
+    }
+
+    /// Creates a symmetric table from a table, returns `None` if it's not symmetric.
+    pub fn from_table(table: ArrTable<K, K, T, N, N>) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        if table.is_symmetric() {
+            Some(Self { table })
+        } else {
+            None
+        }
+    }
+
+    /// Get an underlying table.
+    pub const fn as_table(&self) -> &ArrTable<K, K, T, N, N> {
+        &self.table
+    }
+
+    /// Converts the table into an underlying table.
+    pub fn into_table(self) -> ArrTable<K, K, T, N, N> {
+        self.table
+    }
+
+    /// Get an object by a pair of ids.
+    pub const fn get(&self, a: K, b: K) -> &T {
+        self.table.get(a, b)
+    }
+
+    /// Set an object by a pair of ids and by the mirrored pair.
+    pub fn set(&mut self, a: K, b: K, value: T)
+    where
+        T: Clone,
+    {
+        self.table.set(b, a, value.clone());
+        self.table.set(a, b, value);
+    }
+
+    /// Get a row by an id.
+    pub const fn row(&self, row: K) -> &ArrMap<K, T, N> {
+        self.table.row(row)
+    }
+
+    /// Get a column by an id as a map of references.
+    pub fn column(&self, col: K) -> ArrMap<K, &T, N> {
+        self.table.column(col)
+    }
+
+    /// Map values to new ones providing their keys (in ordered form as in [`ArrSymTable::from_fn`]).
+    pub fn map_with_key<U, F>(&self, mut func: F) -> ArrSymTable<K, U, N>
+    where
+        F: FnMut(K, K, &T) -> U,
+        U: Clone,
+    {
+        ArrSymTable::from_fn(|a, b| func(a, b, self.get(a, b)))
+    }
+}
+
+impl<K: Key, T, const N: usize> core::ops::Index<(K, K)> for ArrSymTable<K, T, N> {
+    type Output = T;
+
+    fn index(&self, (a, b): (K, K)) -> &Self::Output {
+        self.get(a, b)
+    }
+}
+
+impl<K: Key, T: Default, const N: usize> Default for ArrSymTable<K, T, N> {
+    fn default() -> Self {
+        Self {
+            table: ArrTable::default(),
+        }
+    }
+}
+
+impl<K: Key, T, const N: usize> From<ArrSymTable<K, T, N>> for ArrTable<K, K, T, N, N> {
+    fn from(table: ArrSymTable<K, T, N>) -> Self {
+        table.into_table()
+    }
+}
+
+impl<K: Key, T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrSymTable<K, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.table.fmt(f)
+    }
+}
@@ -23,15 +23,8 @@ pub const EARTH: EarthMap<usize> = maparr!(
     Australia           = 7000,
 );
 
-pub const EARTH_AFRICA: usize = *EARTH.get(EarthMap::Africa);
+pub const EARTH_AFRICA: usize = *EARTH.get(EarthMapKey::Africa);
 
-pub const LUA: EarthMap<usize> = maparr!(
-    EarthMap;
-    Asia                = *EARTH.get(EarthMap::Asia) * 2,
-    Africa              = *EARTH.get(EarthMap::Africa) * 2,
-    America_North       = *EARTH.get(EarthMap::America_North) * 2,
-    America_South       = *EARTH.get(EarthMap::America_South) * 2,
-    Antarctica          = *EARTH.get(EarthMap::Antarctica) * 2,
-    Europe              = *EARTH.get(EarthMap::Europe) * 2,
-    Australia           = *EARTH.get(EarthMap::Australia) * 2,
-);
+pub const LUA: EarthMap<usize> = maparr!(EarthMap; for id => *EARTH.get(id) * 2);
+
+pub const EARTH_ASIA: EarthMap<usize> = maparr!(EarthMap; Asia = 10, ..EARTH);